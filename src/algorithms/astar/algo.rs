@@ -68,6 +68,14 @@ impl<T: FloatNumber, Heur: Heuristic<T>, H: PriorityQueue<T>> SsspAlgorithmInfo
     fn supports_negative_weights(&self) -> bool {
         false
     }
+
+    fn target(&self) -> Option<usize> {
+        self.config.target()
+    }
+
+    fn requires_target(&self) -> bool {
+        true
+    }
 }
 
 impl<T, N, G, Heur, H> SsspAlgorithm<T, N, G> for AStar<T, Heur, H>
@@ -98,7 +106,8 @@ where
             let g_u = buffers.dist[u];
 
             let h_u = self.config.heuristic.estimate(u, target);
-            if self.config.lazy_deletion && f_u > g_u + h_u {
+            let skip_stale = self.config.lazy_deletion || !H::DECREASE_KEY;
+            if skip_stale && f_u > g_u + h_u {
                 continue;
             }
 
@@ -121,7 +130,7 @@ where
                 ) {
                     let h_v = self.config.heuristic.estimate(v, target);
                     let f_v = buffers.dist[v] + h_v;
-                    self.heap.push(f_v, v);
+                    self.heap.decrease_key(f_v, v);
                 }
             });
         }
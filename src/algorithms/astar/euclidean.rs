@@ -0,0 +1,69 @@
+use crate::utils::{FloatNumber, Graph};
+use num_traits::Float;
+
+use super::config::Heuristic;
+
+/// Straight-line distance between vertex coordinates, times `scale`.
+/// Admissible when every edge weight is at least `scale` times its length.
+#[derive(Clone, Debug)]
+pub struct EuclideanHeuristic<T: FloatNumber> {
+    coords: Vec<(T, T)>,
+    scale: T,
+}
+
+impl<T: FloatNumber> EuclideanHeuristic<T> {
+    pub fn new(coords: Vec<(T, T)>, scale: T) -> Self {
+        Self { coords, scale }
+    }
+
+    /// Largest scale that keeps the bound admissible on `graph`:
+    /// the minimum weight / length over all edges.
+    pub fn fitted<G: Graph<T>>(graph: &G, coords: Vec<(T, T)>) -> Self {
+        debug_assert!(coords.len() == graph.n(), "One coordinate per vertex");
+
+        let mut scale = T::infinity();
+        let base = Self::new(coords, T::one());
+        for u in 0..graph.n() {
+            graph.for_each_out_edge(u, |v, w| {
+                let length = base.length(u, v);
+                if length > T::zero() && w / length < scale {
+                    scale = w / length;
+                }
+            });
+        }
+        if scale.is_infinite() {
+            scale = T::zero(); // No edge with length: nothing to bound
+        }
+        Self { scale, ..base }
+    }
+
+    pub fn scale(&self) -> T {
+        self.scale
+    }
+
+    pub fn coords(&self) -> &[(T, T)] {
+        &self.coords
+    }
+
+    #[inline]
+    fn length(&self, u: usize, v: usize) -> T {
+        let (ux, uy) = self.coords[u];
+        let (vx, vy) = self.coords[v];
+        Float::sqrt((ux - vx) * (ux - vx) + (uy - vy) * (uy - vy))
+    }
+}
+
+impl<T: FloatNumber> Heuristic<T> for EuclideanHeuristic<T> {
+    #[inline]
+    fn estimate(&self, vertex: usize, target: usize) -> T {
+        self.scale * self.length(vertex, target)
+    }
+}
+
+/// Borrowed coordinates, so repeated `AStar` runs don't clone them.
+impl<T: FloatNumber> Heuristic<T> for &EuclideanHeuristic<T> {
+    #[inline]
+    fn estimate(&self, vertex: usize, target: usize) -> T {
+        self.scale * self.length(vertex, target)
+    }
+}
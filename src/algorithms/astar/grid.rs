@@ -0,0 +1,64 @@
+use crate::utils::{FloatNumber, GridGraph};
+
+use super::config::Heuristic;
+
+/// Row/column offsets between two vertices of a `cols`-wide grid.
+#[inline]
+fn offsets(cols: usize, u: usize, v: usize) -> (usize, usize) {
+    let (ur, uc) = (u / cols, u % cols);
+    let (vr, vc) = (v / cols, v % cols);
+    (ur.abs_diff(vr), uc.abs_diff(vc))
+}
+
+/// |dr| + |dc| steps at the cheapest cell cost. Admissible on 4-connected grids.
+#[derive(Clone, Copy, Debug)]
+pub struct ManhattanHeuristic<T: FloatNumber> {
+    cols: usize,
+    scale: T,
+}
+
+impl<T: FloatNumber> ManhattanHeuristic<T> {
+    pub fn new(cols: usize, scale: T) -> Self {
+        Self { cols, scale }
+    }
+
+    pub fn for_grid(grid: &GridGraph<T>) -> Self {
+        Self::new(grid.cols(), grid.min_cost())
+    }
+}
+
+impl<T: FloatNumber> Heuristic<T> for ManhattanHeuristic<T> {
+    #[inline]
+    fn estimate(&self, vertex: usize, target: usize) -> T {
+        let (dr, dc) = offsets(self.cols, vertex, target);
+        self.scale * T::from(dr + dc).unwrap()
+    }
+}
+
+/// max + (sqrt(2) - 1) * min of the offsets, at the cheapest cell cost.
+/// Exact on open unit grids with diagonals; admissible on 4- and 8-connected grids.
+#[derive(Clone, Copy, Debug)]
+pub struct OctileHeuristic<T: FloatNumber> {
+    cols: usize,
+    scale: T,
+}
+
+impl<T: FloatNumber> OctileHeuristic<T> {
+    pub fn new(cols: usize, scale: T) -> Self {
+        Self { cols, scale }
+    }
+
+    pub fn for_grid(grid: &GridGraph<T>) -> Self {
+        Self::new(grid.cols(), grid.min_cost())
+    }
+}
+
+impl<T: FloatNumber> Heuristic<T> for OctileHeuristic<T> {
+    #[inline]
+    fn estimate(&self, vertex: usize, target: usize) -> T {
+        let (dr, dc) = offsets(self.cols, vertex, target);
+        let (lo, hi) = if dr < dc { (dr, dc) } else { (dc, dr) };
+        let diagonal = T::from(std::f64::consts::SQRT_2 - 1.0).unwrap();
+        self.scale * (T::from(hi).unwrap() + diagonal * T::from(lo).unwrap())
+    }
+}
@@ -0,0 +1,288 @@
+use crate::algorithms::heaps::BinaryHeap;
+use crate::algorithms::{Dijkstra, SsspAlgorithm};
+use crate::utils::{parallel_sssp, transpose_of, FloatNumber, Graph, SsspBuffers, PARENT_NONE};
+use nalgebra::Dyn;
+
+use super::config::Heuristic;
+
+/// How landmarks are picked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LandmarkSelection {
+    /// Repeatedly take the vertex farthest from the chosen set.
+    #[default]
+    Farthest,
+    /// Goldberg-Werneck: descend shortest-path trees into regions with weak bounds.
+    Avoid,
+}
+
+#[derive(Clone, Debug)]
+pub struct LandmarkConfig {
+    pub count: usize,
+    pub selection: LandmarkSelection,
+    pub seed: u64, // Start / root choice
+}
+
+impl Default for LandmarkConfig {
+    fn default() -> Self {
+        Self {
+            count: 8,
+            selection: LandmarkSelection::Farthest,
+            seed: 1,
+        }
+    }
+}
+
+impl LandmarkConfig {
+    pub fn new(count: usize, selection: LandmarkSelection) -> Self {
+        Self {
+            count,
+            selection,
+            ..Self::default()
+        }
+    }
+}
+
+/// ALT heuristic: triangle-inequality bounds from precomputed landmark distances.
+/// Admissible and consistent on any graph with non-negative weights.
+#[derive(Clone, Debug)]
+pub struct LandmarkHeuristic<T: FloatNumber> {
+    n: usize,
+    landmarks: Vec<usize>,
+    from: Vec<T>, // from[i * n + v] = d(L_i, v)
+    to: Vec<T>,   // to[i * n + v] = d(v, L_i)
+}
+
+impl<T: FloatNumber> LandmarkHeuristic<T> {
+    /// `count` landmarks with farthest selection.
+    pub fn new<G: Graph<T> + Sync>(graph: &G, count: usize) -> Self {
+        Self::with_config(
+            graph,
+            &LandmarkConfig::new(count, LandmarkSelection::Farthest),
+        )
+    }
+
+    pub fn with_config<G: Graph<T> + Sync>(graph: &G, config: &LandmarkConfig) -> Self {
+        let landmarks = match config.selection {
+            LandmarkSelection::Farthest => select_farthest(graph, config),
+            LandmarkSelection::Avoid => select_avoid(graph, config),
+        };
+        Self::from_landmarks(graph, landmarks)
+    }
+
+    /// Build tables for a given landmark set.
+    pub fn from_landmarks<G: Graph<T> + Sync>(graph: &G, landmarks: Vec<usize>) -> Self {
+        let n = graph.n();
+        let reverse = transpose_of(graph);
+
+        let dijkstra = || Dijkstra::<T, BinaryHeap<T>>::new();
+        let forward = parallel_sssp::<T, Dyn, _, _, _>(graph, &landmarks, dijkstra);
+        let backward = parallel_sssp::<T, Dyn, _, _, _>(&reverse, &landmarks, dijkstra);
+
+        let flatten = |buffers: &[SsspBuffers<T, Dyn>]| {
+            buffers
+                .iter()
+                .flat_map(|b| b.dist.iter().copied())
+                .collect::<Vec<T>>()
+        };
+
+        Self {
+            n,
+            from: flatten(&forward.buffers),
+            to: flatten(&backward.buffers),
+            landmarks,
+        }
+    }
+
+    pub fn landmarks(&self) -> &[usize] {
+        &self.landmarks
+    }
+
+    /// Lower bound on d(v, t): max over landmarks of
+    /// d(L, t) - d(L, v) and d(v, L) - d(t, L).
+    /// Infinite when a landmark proves t unreachable from v.
+    pub fn bound(&self, v: usize, t: usize) -> T {
+        let mut best = T::zero();
+        for i in 0..self.landmarks.len() {
+            let row = i * self.n;
+            let terms = [
+                (self.from[row + t], self.from[row + v]),
+                (self.to[row + v], self.to[row + t]),
+            ];
+            for (a, b) in terms {
+                if b.is_infinite() {
+                    continue; // No information
+                }
+                if a - b > best {
+                    best = a - b;
+                }
+            }
+        }
+        best
+    }
+}
+
+impl<T: FloatNumber> Heuristic<T> for LandmarkHeuristic<T> {
+    #[inline]
+    fn estimate(&self, vertex: usize, target: usize) -> T {
+        self.bound(vertex, target)
+    }
+}
+
+/// Borrowed tables, so repeated `AStar` runs don't clone them.
+impl<T: FloatNumber> Heuristic<T> for &LandmarkHeuristic<T> {
+    #[inline]
+    fn estimate(&self, vertex: usize, target: usize) -> T {
+        self.bound(vertex, target)
+    }
+}
+
+/// Deterministic vertex picks (xorshift).
+struct Picker {
+    state: u64,
+}
+
+impl Picker {
+    fn new(seed: u64) -> Self {
+        Self { state: seed.max(1) }
+    }
+
+    fn vertex(&mut self, n: usize) -> usize {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        (self.state % n as u64) as usize
+    }
+}
+
+fn sssp_from<T: FloatNumber, G: Graph<T>>(graph: &G, source: usize) -> SsspBuffers<T, Dyn> {
+    let mut buffers = SsspBuffers::new_inf(Dyn(graph.n()));
+    Dijkstra::<T, BinaryHeap<T>>::new().run(graph, source, &mut buffers);
+    buffers
+}
+
+/// Non-landmark vertex with the largest `score` (infinite = unreached, best).
+fn argmax_free<T: FloatNumber>(score: &[T], chosen: &[bool]) -> Option<usize> {
+    let mut best: Option<usize> = None;
+    for v in (0..score.len()).filter(|&v| !chosen[v]) {
+        if best.is_none_or(|b| score[v] > score[b]) {
+            best = Some(v);
+        }
+    }
+    best
+}
+
+fn select_farthest<T: FloatNumber, G: Graph<T>>(graph: &G, config: &LandmarkConfig) -> Vec<usize> {
+    let n = graph.n();
+    let mut landmarks = Vec::new();
+    if n == 0 {
+        return landmarks;
+    }
+
+    let mut chosen = vec![false; n];
+    let start = Picker::new(config.seed).vertex(n);
+    let mut min_dist: Vec<T> = sssp_from(graph, start).dist.iter().copied().collect();
+
+    while landmarks.len() < config.count {
+        let Some(l) = argmax_free(&min_dist, &chosen) else {
+            break;
+        };
+        let dist = sssp_from(graph, l).dist;
+
+        // The start vertex only seeds the first pick
+        if landmarks.is_empty() {
+            min_dist.fill(T::infinity());
+        }
+        for (m, &d) in min_dist.iter_mut().zip(dist.iter()) {
+            if d < *m {
+                *m = d;
+            }
+        }
+
+        chosen[l] = true;
+        landmarks.push(l);
+    }
+    landmarks
+}
+
+fn select_avoid<T: FloatNumber, G: Graph<T>>(graph: &G, config: &LandmarkConfig) -> Vec<usize> {
+    let n = graph.n();
+    let mut chosen = vec![false; n];
+    let mut picker = Picker::new(config.seed);
+
+    // Bounds from the landmarks chosen so far, grown one at a time
+    let reverse = transpose_of(graph);
+    let mut current = LandmarkHeuristic {
+        n,
+        landmarks: Vec::new(),
+        from: Vec::new(),
+        to: Vec::new(),
+    };
+
+    while current.landmarks.len() < config.count.min(n) {
+        let root = picker.vertex(n);
+        let tree = sssp_from(graph, root);
+
+        // Weight = how much the current bound underestimates d(root, v)
+        let mut size = vec![T::zero(); n];
+        for (v, s) in size.iter_mut().enumerate() {
+            let d = tree.dist[v];
+            if !d.is_infinite() {
+                *s = d - current.bound(root, v);
+            }
+        }
+
+        // Children lists, then subtree sums bottom-up (reverse preorder)
+        let mut children = vec![Vec::new(); n];
+        for v in 0..n {
+            let p = tree.parent[v];
+            if p != PARENT_NONE {
+                children[p].push(v);
+            }
+        }
+        let mut order = vec![root];
+        let mut i = 0;
+        while i < order.len() {
+            order.extend_from_slice(&children[order[i]]);
+            i += 1;
+        }
+
+        let mut blocked = vec![false; n];
+        for &v in order.iter().rev() {
+            blocked[v] = chosen[v] || children[v].iter().any(|&c| blocked[c]);
+            if blocked[v] {
+                size[v] = T::zero();
+            } else {
+                let below = children[v].iter().fold(T::zero(), |acc, &c| acc + size[c]);
+                size[v] += below;
+            }
+        }
+
+        // Descend towards the heaviest landmark-free subtree
+        let landmark = if blocked[root] {
+            argmax_free(&tree.dist.iter().copied().collect::<Vec<T>>(), &chosen)
+        } else {
+            let mut v = root;
+            while let Some(&c) = children[v]
+                .iter()
+                .filter(|&&c| !blocked[c])
+                .max_by(|&&a, &&b| size[a].partial_cmp(&size[b]).unwrap())
+            {
+                v = c;
+            }
+            Some(v)
+        };
+
+        let Some(l) = landmark else {
+            break;
+        };
+        current.landmarks.push(l);
+        current
+            .from
+            .extend(sssp_from(graph, l).dist.iter().copied());
+        current
+            .to
+            .extend(sssp_from(&reverse, l).dist.iter().copied());
+        chosen[l] = true;
+    }
+    current.landmarks
+}
@@ -1,8 +1,14 @@
 mod algo;
 mod config;
+mod euclidean;
+mod grid;
+mod landmarks;
 
 pub use algo::AStar;
 pub use config::{AStarConfig, FnHeuristic, Heuristic, ZeroHeuristic};
+pub use euclidean::EuclideanHeuristic;
+pub use grid::{ManhattanHeuristic, OctileHeuristic};
+pub use landmarks::{LandmarkConfig, LandmarkHeuristic, LandmarkSelection};
 
 use crate::algorithms::heaps::BinaryHeap;
 use crate::algorithms::{SsspAlgorithm, SsspResult};
@@ -1,4 +1,7 @@
-use crate::algorithms::{finalize_sssp, init_sssp, SsspAlgorithm, SsspAlgorithmInfo, SsspResult};
+use crate::algorithms::{
+    finalize_sssp, init_sssp, HasSsspConfig, NegativeCycle, SsspAlgorithm, SsspAlgorithmInfo,
+    SsspResult,
+};
 use crate::utils::{FloatNumber, Graph, SsspBuffers};
 use nalgebra::{allocator::Allocator, DefaultAllocator, Dim};
 use rayon::prelude::*;
@@ -57,6 +60,10 @@ impl<T: FloatNumber> SsspAlgorithmInfo for BellmanFord<T> {
     fn supports_negative_weights(&self) -> bool {
         true
     }
+
+    fn target(&self) -> Option<usize> {
+        self.config.sssp_config().early_stop
+    }
 }
 
 impl<T, N, G> SsspAlgorithm<T, N, G> for BellmanFord<T>
@@ -85,9 +92,11 @@ where
             }
         }
 
-        let negative_cycle = detect_negative_cycle(graph, buffers);
+        let violation = detect_negative_cycle(graph, buffers);
 
-        finalize_sssp(buffers, iterations, negative_cycle)
+        let mut result = finalize_sssp(buffers, iterations, violation.is_some());
+        result.cycle = violation.map(|(u, v)| extract_cycle(graph, buffers, u, v));
+        result
     }
 }
 
@@ -169,7 +178,8 @@ where
     any_improved
 }
 
-fn detect_negative_cycle<T, N, G>(graph: &G, buffers: &SsspBuffers<T, N>) -> bool
+/// Find an edge (u, v) that still relaxes after |V| - 1 rounds.
+fn detect_negative_cycle<T, N, G>(graph: &G, buffers: &SsspBuffers<T, N>) -> Option<(usize, usize)>
 where
     T: FloatNumber,
     N: Dim,
@@ -179,18 +189,51 @@ where
     let n = graph.n();
     let dist_slice = buffers.dist.as_slice();
 
-    (0..n).into_par_iter().any(|u| {
+    (0..n).into_par_iter().find_map_any(|u| {
         let d_u = dist_slice[u];
         if d_u.is_infinite() {
-            return false;
+            return None;
         }
 
-        let mut has_cycle = false;
+        let mut violation = None;
         graph.for_each_out_edge(u, |v, w| {
-            if d_u + w < dist_slice[v] {
-                has_cycle = true;
+            if violation.is_none() && d_u + w < dist_slice[v] {
+                violation = Some((u, v));
             }
         });
-        has_cycle
+        violation
     })
 }
+
+/// Walk parents back from v (as if (u, v) were relaxed) into the cycle.
+/// Any parent chain that avoids a cycle would bound dist[v] by a simple path,
+/// so after |V| steps the walk must be on one.
+fn extract_cycle<T, N, G>(
+    graph: &G,
+    buffers: &SsspBuffers<T, N>,
+    u: usize,
+    v: usize,
+) -> NegativeCycle<T>
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T>,
+    DefaultAllocator: Allocator<N>,
+{
+    let pred = |x: usize| if x == v { u } else { buffers.parent[x] };
+
+    let mut x = v;
+    for _ in 0..graph.n() {
+        x = pred(x);
+    }
+
+    let mut vertices = vec![x];
+    let mut y = pred(x);
+    while y != x {
+        vertices.push(y);
+        y = pred(y);
+    }
+    vertices.reverse();
+
+    NegativeCycle::from_vertices(graph, vertices)
+}
@@ -0,0 +1,257 @@
+use crate::algorithms::heaps::{BinaryHeap, PriorityQueue};
+use crate::algorithms::{finalize_sssp, init_sssp, SsspAlgorithm, SsspAlgorithmInfo, SsspResult};
+use crate::utils::{
+    relax_with, transpose_of, BidirectionalGraph, FloatNumber, Graph, RelaxResult, ReverseGraph,
+    SsspBuffers, PARENT_NONE,
+};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim};
+use std::marker::PhantomData;
+
+use super::config::BidirectionalDijkstraConfig;
+
+/// Bidirectional run result, with per-direction scan counts.
+#[derive(Clone, Debug)]
+pub struct BidirectionalResult<T: FloatNumber> {
+    pub sssp: SsspResult<T>, // iterations = forward + backward
+    pub forward_iterations: usize,
+    pub backward_iterations: usize,
+}
+
+#[derive(Debug)]
+pub struct BidirectionalDijkstra<T: FloatNumber, H: PriorityQueue<T> = BinaryHeap<T>> {
+    config: BidirectionalDijkstraConfig,
+    forward: H,
+    backward: H,
+    back_dist: Vec<T>,     // Vertex to target
+    back_next: Vec<usize>, // Next vertex towards target
+    on_path: Vec<bool>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: FloatNumber, H: PriorityQueue<T>> BidirectionalDijkstra<T, H> {
+    pub fn new(target: usize) -> Self {
+        Self::with_config(BidirectionalDijkstraConfig::new(target))
+    }
+
+    pub fn with_config(config: BidirectionalDijkstraConfig) -> Self {
+        Self {
+            config,
+            forward: H::new(),
+            backward: H::new(),
+            back_dist: Vec::new(),
+            back_next: Vec::new(),
+            on_path: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn with_capacity(target: usize, capacity: usize) -> Self {
+        Self {
+            forward: H::with_capacity(capacity),
+            backward: H::with_capacity(capacity),
+            ..Self::new(target)
+        }
+    }
+
+    pub fn config(&self) -> &BidirectionalDijkstraConfig {
+        &self.config
+    }
+
+    pub fn config_mut(&mut self) -> &mut BidirectionalDijkstraConfig {
+        &mut self.config
+    }
+
+    /// Run with a precomputed `reverse`, which must be the transpose of `graph`.
+    /// Only the source-target path (and forward-settled vertices) are exact in `buffers`.
+    pub fn run_with_reverse<N, G, R>(
+        &mut self,
+        graph: &G,
+        reverse: &R,
+        source: usize,
+        buffers: &mut SsspBuffers<T, N>,
+    ) -> BidirectionalResult<T>
+    where
+        N: Dim,
+        G: Graph<T>,
+        R: Graph<T>,
+        DefaultAllocator: Allocator<N>,
+    {
+        debug_assert!(source < graph.n(), "Source vertex out of bounds");
+        debug_assert!(reverse.n() == graph.n(), "Reverse graph size mismatch");
+
+        let target = self.config.target();
+
+        let n = graph.n();
+        init_sssp(buffers, source);
+        self.back_dist.clear();
+        self.back_dist.resize(n, T::infinity());
+        self.back_next.clear();
+        self.back_next.resize(n, PARENT_NONE);
+        self.back_dist[target] = T::zero();
+
+        self.forward.clear();
+        self.backward.clear();
+        self.forward.push(T::zero(), source);
+        self.backward.push(T::zero(), target);
+
+        // Best s-t path seen so far: mu = df[u] + w(u, v) + db[v]
+        let mut mu = if source == target {
+            T::zero()
+        } else {
+            T::infinity()
+        };
+        let mut meeting: Option<(usize, usize)> = None;
+
+        let mut forward_iterations = 0usize;
+        let mut backward_iterations = 0usize;
+        let (mut top_f, mut top_b) = (T::zero(), T::zero());
+
+        let dist = buffers.dist.as_mut_slice();
+        let parent = buffers.parent.as_mut_slice();
+        let back_dist = self.back_dist.as_mut_slice();
+        let back_next = self.back_next.as_mut_slice();
+
+        // Stop once no unsettled pair can beat mu
+        while !self.forward.is_empty() && !self.backward.is_empty() && top_f + top_b < mu {
+            // Grow the smaller radius to keep both balls the same size
+            if top_f <= top_b {
+                let entry = self.forward.pop().unwrap();
+                let (u, d_u) = (entry.vertex, entry.dist);
+                if d_u > dist[u] {
+                    continue;
+                }
+                top_f = d_u;
+                forward_iterations += 1;
+
+                let heap = &mut self.forward;
+                graph.for_each_out_edge(u, |v, w| {
+                    debug_assert!(w >= T::zero(), "Dijkstra requires non-negative weights");
+
+                    if let RelaxResult::Improved = relax_with(dist, parent, u, d_u, v, w) {
+                        heap.decrease_key(dist[v], v);
+                    }
+                    let through = d_u + w + back_dist[v];
+                    if through < mu {
+                        mu = through;
+                        meeting = Some((u, v));
+                    }
+                });
+            } else {
+                let entry = self.backward.pop().unwrap();
+                let (v, d_v) = (entry.vertex, entry.dist);
+                if d_v > back_dist[v] {
+                    continue;
+                }
+                top_b = d_v;
+                backward_iterations += 1;
+
+                let heap = &mut self.backward;
+                reverse.for_each_out_edge(v, |u, w| {
+                    debug_assert!(w >= T::zero(), "Dijkstra requires non-negative weights");
+
+                    if let RelaxResult::Improved = relax_with(back_dist, back_next, v, d_v, u, w) {
+                        heap.decrease_key(back_dist[u], u);
+                    }
+                    let through = dist[u] + w + d_v;
+                    if through < mu {
+                        mu = through;
+                        meeting = Some((u, v));
+                    }
+                });
+            }
+        }
+
+        if let Some((u, v)) = meeting {
+            self.join_path(dist, parent, u, v, mu, target);
+        }
+
+        let iterations = forward_iterations + backward_iterations;
+        BidirectionalResult {
+            sssp: finalize_sssp(buffers, iterations, false),
+            forward_iterations,
+            backward_iterations,
+        }
+    }
+
+    /// Run on a graph that indexes its own in-edges, no reverse copy needed.
+    pub fn run_with_in_edges<N, G>(
+        &mut self,
+        graph: &G,
+        source: usize,
+        buffers: &mut SsspBuffers<T, N>,
+    ) -> BidirectionalResult<T>
+    where
+        N: Dim,
+        G: BidirectionalGraph<T>,
+        DefaultAllocator: Allocator<N>,
+    {
+        self.run_with_reverse(graph, &ReverseGraph::new(graph), source, buffers)
+    }
+
+    /// Hang the backward half (v .. target) off u in the forward tree, so
+    /// `path_to(target)` walks s .. u -> v .. target. Vertices already on the
+    /// forward path (zero-weight cycles) keep their forward parent.
+    fn join_path(
+        &mut self,
+        dist: &mut [T],
+        parent: &mut [usize],
+        u: usize,
+        v: usize,
+        mu: T,
+        target: usize,
+    ) {
+        self.on_path.clear();
+        self.on_path.resize(dist.len(), false);
+        let mut x = u;
+        while x != PARENT_NONE {
+            self.on_path[x] = true;
+            x = parent[x];
+        }
+
+        let (mut prev, mut x) = (u, v);
+        loop {
+            if !self.on_path[x] {
+                parent[x] = prev;
+                dist[x] = mu - self.back_dist[x];
+            }
+            if x == target {
+                break;
+            }
+            prev = x;
+            x = self.back_next[x];
+        }
+    }
+}
+
+impl<T: FloatNumber, H: PriorityQueue<T>> SsspAlgorithmInfo for BidirectionalDijkstra<T, H> {
+    fn name(&self) -> &'static str {
+        "Bidirectional Dijkstra"
+    }
+
+    fn supports_negative_weights(&self) -> bool {
+        false
+    }
+
+    fn target(&self) -> Option<usize> {
+        Some(self.config.target())
+    }
+
+    fn requires_target(&self) -> bool {
+        true
+    }
+}
+
+impl<T, N, G, H> SsspAlgorithm<T, N, G> for BidirectionalDijkstra<T, H>
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T>,
+    H: PriorityQueue<T>,
+    DefaultAllocator: Allocator<N>,
+{
+    /// Builds the reversed graph on every call; prefer `run_with_reverse` for repeated queries.
+    fn run(&mut self, graph: &G, source: usize, buffers: &mut SsspBuffers<T, N>) -> SsspResult<T> {
+        let reverse = transpose_of(graph);
+        self.run_with_reverse(graph, &reverse, source, buffers).sssp
+    }
+}
@@ -0,0 +1,27 @@
+use crate::algorithms::{HasSsspConfig, SsspConfig};
+
+/// No `Default`: a target is required.
+#[derive(Clone, Debug)]
+pub struct BidirectionalDijkstraConfig {
+    target: usize,
+    base: SsspConfig, // early_stop = Some(target)
+}
+
+impl BidirectionalDijkstraConfig {
+    pub fn new(target: usize) -> Self {
+        Self {
+            target,
+            base: SsspConfig::with_target(target),
+        }
+    }
+
+    pub fn target(&self) -> usize {
+        self.target
+    }
+}
+
+impl HasSsspConfig for BidirectionalDijkstraConfig {
+    fn sssp_config(&self) -> &SsspConfig {
+        &self.base
+    }
+}
@@ -0,0 +1,26 @@
+mod algo;
+mod config;
+
+pub use algo::{BidirectionalDijkstra, BidirectionalResult};
+pub use config::BidirectionalDijkstraConfig;
+
+use crate::algorithms::heaps::BinaryHeap;
+use crate::algorithms::{SsspAlgorithm, SsspResult};
+use crate::utils::{FloatNumber, Graph, SsspBuffers};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim};
+
+/// One-shot bidirectional Dijkstra execute (builds the reversed graph).
+pub fn cheeky_bidirectional_dijkstra<T, N, G>(
+    graph: &G,
+    source: usize,
+    target: usize,
+    buffers: &mut SsspBuffers<T, N>,
+) -> SsspResult<T>
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T>,
+    DefaultAllocator: Allocator<N>,
+{
+    BidirectionalDijkstra::<T, BinaryHeap<T>>::new(target).run(graph, source, buffers)
+}
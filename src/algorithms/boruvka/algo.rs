@@ -0,0 +1,155 @@
+use crate::algorithms::{
+    finalize_mst, init_mst, root_forest, MstAlgorithm, MstAlgorithmInfo, MstResult,
+};
+use crate::utils::{ConcurrentDisjointSet, FloatNumber, Graph, MstBuffers};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim};
+use rayon::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::config::BoruvkaConfig;
+
+const NO_EDGE: usize = usize::MAX;
+
+/// Minimum spanning forest by rounds of parallel cheapest-edge contraction.
+#[derive(Debug)]
+pub struct Boruvka<T: FloatNumber> {
+    config: BoruvkaConfig,
+    forest: Vec<Vec<(usize, T)>>, // Accepted edges, both directions
+}
+
+impl<T: FloatNumber> Boruvka<T> {
+    pub fn new() -> Self {
+        Self::with_config(BoruvkaConfig::default())
+    }
+
+    pub fn with_config(config: BoruvkaConfig) -> Self {
+        Self {
+            config,
+            forest: Vec::new(),
+        }
+    }
+
+    pub fn config(&self) -> &BoruvkaConfig {
+        &self.config
+    }
+
+    pub fn config_mut(&mut self) -> &mut BoruvkaConfig {
+        &mut self.config
+    }
+
+    /// Accepted (w, lo, hi) edges, in round order. Returns the round count.
+    fn contract<G: Graph<T> + Sync>(
+        &self,
+        graph: &G,
+        accepted: &mut Vec<(T, usize, usize)>,
+    ) -> usize {
+        let n = graph.n();
+
+        // Canonical (w, lo, hi), sorted: position is a strict total order, so ties are deterministic
+        let mut edges: Vec<(T, usize, usize)> = (0..n)
+            .into_par_iter()
+            .flat_map_iter(|u| {
+                let mut out = Vec::new();
+                graph.for_each_out_edge(u, |v, w| {
+                    if u != v {
+                        out.push((w, u.min(v), u.max(v)));
+                    }
+                });
+                out
+            })
+            .collect();
+        edges.par_sort_unstable_by(|a, b| a.partial_cmp(b).expect("Edge weight is NaN"));
+        edges.dedup();
+
+        let sets = ConcurrentDisjointSet::new(n);
+        let cheapest: Vec<AtomicUsize> = (0..n).map(|_| AtomicUsize::new(NO_EDGE)).collect();
+        let mut rounds = 0usize;
+
+        while !edges.is_empty() {
+            // Cheapest outgoing edge per component, as the smallest edge position
+            cheapest
+                .par_iter()
+                .for_each(|c| c.store(NO_EDGE, Ordering::Relaxed));
+            edges.par_iter().enumerate().for_each(|(i, &(_, u, v))| {
+                let (cu, cv) = (sets.find(u), sets.find(v));
+                cheapest[cu].fetch_min(i, Ordering::Relaxed);
+                cheapest[cv].fetch_min(i, Ordering::Relaxed);
+            });
+
+            // Two components picking each other share the same edge
+            let mut picked: Vec<usize> = cheapest
+                .par_iter()
+                .map(|c| c.load(Ordering::Relaxed))
+                .filter(|&i| i != NO_EDGE)
+                .collect();
+            picked.par_sort_unstable();
+            picked.dedup();
+
+            picked.par_iter().for_each(|&i| {
+                let (_, u, v) = edges[i];
+                let merged = sets.union(u, v);
+                debug_assert!(merged, "Borůvka picked a cycle edge");
+            });
+            accepted.extend(picked.iter().map(|&i| edges[i]));
+            rounds += 1;
+
+            // Drop edges now inside one component
+            edges = edges
+                .into_par_iter()
+                .filter(|&(_, u, v)| sets.find(u) != sets.find(v))
+                .collect();
+        }
+        rounds
+    }
+}
+
+impl<T: FloatNumber> Default for Boruvka<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FloatNumber> MstAlgorithmInfo for Boruvka<T> {
+    fn name(&self) -> &'static str {
+        "Borůvka"
+    }
+}
+
+impl<T, N, G> MstAlgorithm<T, N, G> for Boruvka<T>
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T> + Sync,
+    DefaultAllocator: Allocator<N>,
+{
+    /// Spans every component like `Kruskal`; iterations = contraction rounds.
+    fn run(&mut self, graph: &G, source: usize, buffers: &mut MstBuffers<T, N>) -> MstResult<T> {
+        debug_assert!(source < graph.n(), "Source vertex out of bounds");
+
+        let n = graph.n();
+        init_mst(buffers, source);
+
+        let mut accepted = Vec::new();
+        let rounds = if self.config.parallel {
+            self.contract(graph, &mut accepted)
+        } else {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(1)
+                .build()
+                .expect("Failed to build single-thread pool")
+                .install(|| self.contract(graph, &mut accepted))
+        };
+
+        self.forest.iter_mut().for_each(Vec::clear);
+        self.forest.resize_with(n, Vec::new);
+        for &(w, u, v) in &accepted {
+            self.forest[u].push((v, w));
+            self.forest[v].push((u, w));
+        }
+        root_forest(buffers, &self.forest, source);
+
+        let mut result = finalize_mst(buffers, rounds, n);
+        result.is_connected = accepted.len() + 1 == n;
+        result
+    }
+}
@@ -0,0 +1,22 @@
+/// Configuration for Borůvka's MST algorithm.
+#[derive(Clone, Debug)]
+pub struct BoruvkaConfig {
+    pub parallel: bool, // Scan and contract across threads
+}
+
+impl Default for BoruvkaConfig {
+    fn default() -> Self {
+        Self { parallel: true }
+    }
+}
+
+impl BoruvkaConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn sequential(mut self) -> Self {
+        self.parallel = false;
+        self
+    }
+}
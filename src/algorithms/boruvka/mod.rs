@@ -0,0 +1,24 @@
+mod algo;
+mod config;
+
+pub use algo::Boruvka;
+pub use config::BoruvkaConfig;
+
+use crate::algorithms::{MstAlgorithm, MstResult};
+use crate::utils::{FloatNumber, Graph, MstBuffers};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim};
+
+/// One-shot parallel Borůvka MST execute. Covers every component; `source` roots its own tree.
+pub fn cheeky_boruvka<T, N, G>(
+    graph: &G,
+    source: usize,
+    buffers: &mut MstBuffers<T, N>,
+) -> MstResult<T>
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T> + Sync,
+    DefaultAllocator: Allocator<N>,
+{
+    Boruvka::<T>::new().run(graph, source, buffers)
+}
@@ -1,5 +1,9 @@
-use crate::utils::{ApspBuffers, FloatNumber, Graph, MstBuffers, SsspBuffers};
+use crate::utils::{ApspBuffers, FloatNumber, Graph, MstBuffers, SsspBuffers, MST_PARENT_NONE};
 use nalgebra::{allocator::Allocator, DefaultAllocator, Dim};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::error::{check_buffer_len, check_source, check_target, check_weights, SsspError};
 
 // =============================================================================
 // Config
@@ -43,27 +47,33 @@ impl HasSsspConfig for SsspConfig {
 // =============================================================================
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
 pub struct SsspResult<T: FloatNumber> {
     pub iterations: usize,
     pub negative_cycle: bool,
     pub vertices_reached: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::serialization::float"))]
     pub total_distance: T,
+    pub cycle: Option<NegativeCycle<T>>, // Set by algorithms that extract the cycle
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
 pub struct MstResult<T: FloatNumber> {
     pub iterations: usize,
     pub vertices_in_mst: usize,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::serialization::float"))]
     pub total_weight: T,
     pub is_connected: bool,
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
 pub struct ApspResult<T: FloatNumber> {
     pub iterations: usize,
     pub negative_cycle: bool,
     pub pairs_reached: usize,
-    _phantom: std::marker::PhantomData<T>,
+    pub cycle: Option<NegativeCycle<T>>,
 }
 
 impl<T: FloatNumber> ApspResult<T> {
@@ -72,11 +82,44 @@ impl<T: FloatNumber> ApspResult<T> {
             iterations,
             negative_cycle,
             pairs_reached,
-            _phantom: std::marker::PhantomData,
+            cycle: None,
         }
     }
 }
 
+/// Negative cycle v0 -> v1 -> ... -> v(k-1) -> v0 (first vertex not repeated).
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
+pub struct NegativeCycle<T: FloatNumber> {
+    pub vertices: Vec<usize>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::utils::serialization::float"))]
+    pub weight: T,
+}
+
+impl<T: FloatNumber> NegativeCycle<T> {
+    /// Build from a vertex sequence, summing the cheapest parallel edge per hop.
+    pub(crate) fn from_vertices<G: Graph<T>>(graph: &G, vertices: Vec<usize>) -> Self {
+        let mut weight = T::zero();
+        for (i, &u) in vertices.iter().enumerate() {
+            let v = vertices[(i + 1) % vertices.len()];
+            let mut best = T::infinity();
+            graph.for_each_out_edge(u, |to, w| {
+                if to == v && w < best {
+                    best = w;
+                }
+            });
+            weight += best;
+        }
+        Self { vertices, weight }
+    }
+
+    /// Cycle edges (u, v) in order, including the closing edge.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let k = self.vertices.len();
+        (0..k).map(move |i| (self.vertices[i], self.vertices[(i + 1) % k]))
+    }
+}
+
 // =============================================================================
 // Traits
 // =============================================================================
@@ -84,6 +127,15 @@ impl<T: FloatNumber> ApspResult<T> {
 pub trait SsspAlgorithmInfo {
     fn name(&self) -> &'static str;
     fn supports_negative_weights(&self) -> bool;
+
+    /// Configured target (early stop), if any.
+    fn target(&self) -> Option<usize> {
+        None
+    }
+
+    fn requires_target(&self) -> bool {
+        false
+    }
 }
 
 pub trait SsspAlgorithm<T, N, G>: SsspAlgorithmInfo
@@ -94,6 +146,26 @@ where
     DefaultAllocator: Allocator<N>,
 {
     fn run(&mut self, graph: &G, source: usize, buffers: &mut SsspBuffers<T, N>) -> SsspResult<T>;
+
+    /// `run` after validating the source, target, buffer size and every weight (O(m)).
+    fn try_run(
+        &mut self,
+        graph: &G,
+        source: usize,
+        buffers: &mut SsspBuffers<T, N>,
+    ) -> Result<SsspResult<T>, SsspError> {
+        let n = graph.n();
+        check_source(source, n)?;
+        match self.target() {
+            Some(target) => check_target(target, n)?,
+            None if self.requires_target() => return Err(SsspError::MissingTarget),
+            None => {}
+        }
+        check_buffer_len(n, buffers.dist.len())?;
+        check_buffer_len(n, buffers.parent.len())?;
+        check_weights(graph, self.supports_negative_weights())?;
+        Ok(self.run(graph, source, buffers))
+    }
 }
 
 pub trait MstAlgorithmInfo {
@@ -108,6 +180,22 @@ where
     DefaultAllocator: Allocator<N>,
 {
     fn run(&mut self, graph: &G, source: usize, buffers: &mut MstBuffers<T, N>) -> MstResult<T>;
+
+    /// `run` after validating the source, buffer size and every weight (O(m)).
+    fn try_run(
+        &mut self,
+        graph: &G,
+        source: usize,
+        buffers: &mut MstBuffers<T, N>,
+    ) -> Result<MstResult<T>, SsspError> {
+        let n = graph.n();
+        check_source(source, n)?;
+        check_buffer_len(n, buffers.key.len())?;
+        check_buffer_len(n, buffers.parent.len())?;
+        check_buffer_len(n, buffers.in_mst.len())?;
+        check_weights(graph, true)?;
+        Ok(self.run(graph, source, buffers))
+    }
 }
 
 pub trait ApspAlgorithmInfo {
@@ -121,6 +209,21 @@ where
     G: Graph<T>,
 {
     fn run(&mut self, graph: &G, buffers: &mut ApspBuffers<T>) -> ApspResult<T>;
+
+    /// `run` after validating the buffer size and every weight (O(m)).
+    fn try_run(
+        &mut self,
+        graph: &G,
+        buffers: &mut ApspBuffers<T>,
+    ) -> Result<ApspResult<T>, SsspError> {
+        let n = graph.n();
+        let size = n.checked_mul(n).ok_or(SsspError::MatrixTooLarge { n })?;
+        check_buffer_len(n, buffers.n)?;
+        check_buffer_len(size, buffers.dist.len())?;
+        check_buffer_len(size, buffers.next.len())?;
+        check_weights(graph, self.supports_negative_weights())?;
+        Ok(self.run(graph, buffers))
+    }
 }
 
 // =============================================================================
@@ -154,6 +257,7 @@ where
         negative_cycle,
         vertices_reached,
         total_distance,
+        cycle: None,
     }
 }
 
@@ -185,6 +289,41 @@ where
     buffers.set_source(source);
 }
 
+/// Write an undirected forest (adjacency of chosen edges) as parent pointers.
+/// The tree holding `source` is rooted there, every other tree at its smallest vertex.
+pub(crate) fn root_forest<T, N>(
+    buffers: &mut MstBuffers<T, N>,
+    forest: &[Vec<(usize, T)>],
+    source: usize,
+) where
+    T: FloatNumber,
+    N: Dim,
+    DefaultAllocator: Allocator<N>,
+{
+    let mut stack = Vec::new();
+    let roots = std::iter::once(source).chain(0..forest.len());
+    for root in roots {
+        if buffers.in_mst[root] {
+            continue;
+        }
+        buffers.key[root] = T::zero();
+        buffers.parent[root] = MST_PARENT_NONE;
+        buffers.in_mst[root] = true;
+        stack.push(root);
+
+        while let Some(u) = stack.pop() {
+            for &(v, w) in &forest[u] {
+                if !buffers.in_mst[v] {
+                    buffers.key[v] = w;
+                    buffers.parent[v] = u;
+                    buffers.in_mst[v] = true;
+                    stack.push(v);
+                }
+            }
+        }
+    }
+}
+
 pub fn finalize_mst<T, N>(buffers: &MstBuffers<T, N>, iterations: usize, n: usize) -> MstResult<T>
 where
     T: FloatNumber,
@@ -202,3 +341,13 @@ where
         is_connected,
     }
 }
+
+pub fn finalize_apsp<T: FloatNumber>(
+    buffers: &ApspBuffers<T>,
+    iterations: usize,
+    negative_cycle: bool,
+) -> ApspResult<T> {
+    // Count reachable pairs
+    let pairs_reached = buffers.dist.iter().filter(|d| !d.is_infinite()).count();
+    ApspResult::new(iterations, negative_cycle, pairs_reached)
+}
@@ -0,0 +1,202 @@
+use crate::algorithms::heaps::{BinaryHeap, PriorityQueue};
+use crate::utils::{FloatNumber, Graph};
+use std::cmp::Reverse;
+
+use super::config::ContractionConfig;
+use super::hierarchy::{ChEdge, ContractionHierarchy, NO_MIDDLE};
+
+/// Contract vertices in lazily updated edge-difference order.
+pub(super) fn contract<T, G>(graph: &G, config: &ContractionConfig) -> ContractionHierarchy<T>
+where
+    T: FloatNumber,
+    G: Graph<T>,
+{
+    let n = graph.n();
+    let mut builder = Builder::new(graph, config.witness_settle_limit);
+
+    let mut order = std::collections::BinaryHeap::with_capacity(n);
+    for v in 0..n {
+        let (priority, _) = builder.simulate(v);
+        order.push(Reverse((priority, v)));
+    }
+
+    let mut rank = vec![0; n];
+    let mut up = vec![Vec::new(); n];
+    let mut down = vec![Vec::new(); n];
+    let mut next_rank = 0;
+
+    while let Some(Reverse((_, v))) = order.pop() {
+        // Neighbours' contractions may have raised v's priority since it was pushed
+        let (priority, shortcuts) = builder.simulate(v);
+        if let Some(&Reverse((top, _))) = order.peek() {
+            if priority > top {
+                order.push(Reverse((priority, v)));
+                continue;
+            }
+        }
+
+        for (u, x, w) in shortcuts {
+            builder.add_edge(u, x, w, v);
+        }
+        let (outs, ins) = builder.remove(v);
+        up[v] = outs;
+        down[v] = ins;
+        rank[v] = next_rank;
+        next_rank += 1;
+    }
+
+    ContractionHierarchy { rank, up, down }
+}
+
+/// Remaining (uncontracted) graph plus witness search scratch.
+struct Builder<T: FloatNumber> {
+    out: Vec<Vec<ChEdge<T>>>,
+    inc: Vec<Vec<ChEdge<T>>>,
+    contracted_neighbors: Vec<i64>,
+    settle_limit: usize,
+    dist: Vec<T>,
+    touched: Vec<usize>,
+    heap: BinaryHeap<T>,
+}
+
+impl<T: FloatNumber> Builder<T> {
+    fn new<G: Graph<T>>(graph: &G, settle_limit: usize) -> Self {
+        let n = graph.n();
+        let mut builder = Self {
+            out: vec![Vec::new(); n],
+            inc: vec![Vec::new(); n],
+            contracted_neighbors: vec![0; n],
+            settle_limit,
+            dist: vec![T::infinity(); n],
+            touched: Vec::new(),
+            heap: BinaryHeap::new(),
+        };
+
+        for u in 0..n {
+            graph.for_each_out_edge(u, |v, w| {
+                debug_assert!(w >= T::zero(), "Contraction requires non-negative weights");
+                // Self-loops never lie on shortest paths
+                if u != v {
+                    builder.add_edge(u, v, w, NO_MIDDLE);
+                }
+            });
+        }
+        builder
+    }
+
+    /// Insert (u -> v), keeping only the cheapest parallel edge.
+    fn add_edge(&mut self, u: usize, v: usize, w: T, middle: usize) {
+        if let Some(e) = self.out[u].iter_mut().find(|e| e.other == v) {
+            if w < e.w {
+                *e = ChEdge {
+                    other: v,
+                    w,
+                    middle,
+                };
+                let r = self.inc[v].iter_mut().find(|e| e.other == u).unwrap();
+                *r = ChEdge {
+                    other: u,
+                    w,
+                    middle,
+                };
+            }
+            return;
+        }
+        self.out[u].push(ChEdge {
+            other: v,
+            w,
+            middle,
+        });
+        self.inc[v].push(ChEdge {
+            other: u,
+            w,
+            middle,
+        });
+    }
+
+    /// Detach v, returning its (upward) out- and in-edges.
+    fn remove(&mut self, v: usize) -> (Vec<ChEdge<T>>, Vec<ChEdge<T>>) {
+        let outs = std::mem::take(&mut self.out[v]);
+        let ins = std::mem::take(&mut self.inc[v]);
+        for e in &outs {
+            self.inc[e.other].retain(|a| a.other != v);
+            self.contracted_neighbors[e.other] += 1;
+        }
+        for e in &ins {
+            self.out[e.other].retain(|a| a.other != v);
+            self.contracted_neighbors[e.other] += 1;
+        }
+        (outs, ins)
+    }
+
+    /// Shortcuts needed to contract v, and its priority:
+    /// edge difference (shortcuts - removed edges) + contracted neighbours.
+    fn simulate(&mut self, v: usize) -> (i64, Vec<(usize, usize, T)>) {
+        let mut shortcuts = Vec::new();
+        let ins = self.inc[v].clone();
+        let outs = self.out[v].clone();
+
+        for a in &ins {
+            let u = a.other;
+            let max_via = outs
+                .iter()
+                .filter(|o| o.other != u)
+                .map(|o| a.w + o.w)
+                .fold(T::neg_infinity(), |m, d| if d > m { d } else { m });
+            if max_via.is_infinite() {
+                continue;
+            }
+
+            self.witness_search(u, v, max_via);
+            for o in outs.iter().filter(|o| o.other != u) {
+                let via = a.w + o.w;
+                if self.dist[o.other] > via {
+                    shortcuts.push((u, o.other, via));
+                }
+            }
+        }
+
+        let removed = (ins.len() + outs.len()) as i64;
+        let priority = shortcuts.len() as i64 - removed + self.contracted_neighbors[v];
+        (priority, shortcuts)
+    }
+
+    /// Bounded Dijkstra from `source` avoiding `skip`. Leaves upper bounds in `dist`.
+    fn witness_search(&mut self, source: usize, skip: usize, max_dist: T) {
+        for &x in &self.touched {
+            self.dist[x] = T::infinity();
+        }
+        self.touched.clear();
+        self.heap.clear();
+
+        self.dist[source] = T::zero();
+        self.touched.push(source);
+        self.heap.push(T::zero(), source);
+
+        let mut settled = 0usize;
+        while let Some(entry) = self.heap.pop() {
+            let (u, d_u) = (entry.vertex, entry.dist);
+            if d_u > self.dist[u] {
+                continue;
+            }
+            if d_u > max_dist || settled >= self.settle_limit {
+                break;
+            }
+            settled += 1;
+
+            for e in &self.out[u] {
+                if e.other == skip {
+                    continue;
+                }
+                let new_dist = d_u + e.w;
+                if new_dist < self.dist[e.other] {
+                    if self.dist[e.other].is_infinite() {
+                        self.touched.push(e.other);
+                    }
+                    self.dist[e.other] = new_dist;
+                    self.heap.push(new_dist, e.other);
+                }
+            }
+        }
+    }
+}
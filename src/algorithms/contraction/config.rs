@@ -0,0 +1,20 @@
+#[derive(Clone, Debug)]
+pub struct ContractionConfig {
+    pub witness_settle_limit: usize, // Max vertices settled per witness search
+}
+
+impl Default for ContractionConfig {
+    fn default() -> Self {
+        Self {
+            witness_settle_limit: 500,
+        }
+    }
+}
+
+impl ContractionConfig {
+    /// Smaller limits preprocess faster but add more (redundant) shortcuts.
+    pub fn with_witness_limit(mut self, limit: usize) -> Self {
+        self.witness_settle_limit = limit.max(1);
+        self
+    }
+}
@@ -0,0 +1,87 @@
+use crate::utils::{FloatNumber, Graph};
+
+use super::build::contract;
+use super::config::ContractionConfig;
+use super::query::{ChPath, ChQuery};
+
+/// Sentinel for original (non-shortcut) edges.
+pub(super) const NO_MIDDLE: usize = usize::MAX;
+
+/// Hierarchy edge to/from `other`. Shortcuts record the contracted `middle` vertex.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct ChEdge<T: FloatNumber> {
+    pub other: usize,
+    pub w: T,
+    pub middle: usize,
+}
+
+/// Contracted graph: every edge points from lower to higher rank.
+#[derive(Clone, Debug)]
+pub struct ContractionHierarchy<T: FloatNumber> {
+    pub(super) rank: Vec<usize>,
+    pub(super) up: Vec<Vec<ChEdge<T>>>, // u -> other, rank[u] < rank[other]
+    pub(super) down: Vec<Vec<ChEdge<T>>>, // other -> v, rank[v] < rank[other]
+}
+
+impl<T: FloatNumber> ContractionHierarchy<T> {
+    /// Preprocess `graph` (non-negative weights) with the default config.
+    pub fn new<G: Graph<T>>(graph: &G) -> Self {
+        Self::with_config(graph, &ContractionConfig::default())
+    }
+
+    pub fn with_config<G: Graph<T>>(graph: &G, config: &ContractionConfig) -> Self {
+        contract(graph, config)
+    }
+
+    pub fn n(&self) -> usize {
+        self.rank.len()
+    }
+
+    /// Contraction order of v (0 = contracted first).
+    pub fn rank(&self, v: usize) -> usize {
+        self.rank[v]
+    }
+
+    pub fn num_shortcuts(&self) -> usize {
+        self.up
+            .iter()
+            .chain(&self.down)
+            .flatten()
+            .filter(|e| e.middle != NO_MIDDLE)
+            .count()
+    }
+
+    /// Reusable query state; prefer this for repeated queries.
+    pub fn query_engine(&self) -> ChQuery<'_, T> {
+        ChQuery::new(self)
+    }
+
+    /// One-shot query. Allocates O(n) scratch.
+    pub fn query(&self, source: usize, target: usize) -> Option<ChPath<T>> {
+        self.query_engine().query(source, target)
+    }
+
+    /// Hierarchy edge (a -> b), stored at its lower-ranked endpoint.
+    fn edge(&self, a: usize, b: usize) -> &ChEdge<T> {
+        let found = if self.rank[a] < self.rank[b] {
+            self.up[a].iter().find(|e| e.other == b)
+        } else {
+            self.down[b].iter().find(|e| e.other == a)
+        };
+        found.expect("Missing hierarchy edge")
+    }
+
+    /// Append the original vertices of edge (a -> b) to `path`, excluding a.
+    pub(super) fn unpack_into(&self, a: usize, b: usize, path: &mut Vec<usize>) {
+        let mut stack = vec![(a, b)];
+        while let Some((x, y)) = stack.pop() {
+            let middle = self.edge(x, y).middle;
+            if middle == NO_MIDDLE {
+                path.push(y);
+            } else {
+                stack.push((middle, y));
+                stack.push((x, middle));
+            }
+        }
+    }
+}
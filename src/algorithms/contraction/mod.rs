@@ -0,0 +1,11 @@
+//! Contraction Hierarchies for fast point-to-point queries on static graphs.
+//! See `docs/algorithms/contraction.md`.
+
+mod build;
+mod config;
+mod hierarchy;
+mod query;
+
+pub use config::ContractionConfig;
+pub use hierarchy::ContractionHierarchy;
+pub use query::{ChPath, ChQuery};
@@ -0,0 +1,165 @@
+use crate::algorithms::heaps::{BinaryHeap, PriorityQueue};
+use crate::utils::{FloatNumber, PARENT_NONE};
+
+use super::hierarchy::{ChEdge, ContractionHierarchy};
+
+/// Shortest path as original vertex ids, source and target included.
+#[derive(Clone, Debug)]
+pub struct ChPath<T: FloatNumber> {
+    pub distance: T,
+    pub path: Vec<usize>,
+}
+
+/// Bidirectional upward search over a hierarchy, with reusable scratch.
+#[derive(Debug)]
+pub struct ChQuery<'a, T: FloatNumber, H: PriorityQueue<T> = BinaryHeap<T>> {
+    ch: &'a ContractionHierarchy<T>,
+    forward: H,
+    backward: H,
+    dist_f: Vec<T>,
+    dist_b: Vec<T>,
+    parent_f: Vec<usize>,
+    parent_b: Vec<usize>, // Next vertex towards target
+    touched: Vec<usize>,
+}
+
+impl<'a, T: FloatNumber, H: PriorityQueue<T>> ChQuery<'a, T, H> {
+    pub fn new(ch: &'a ContractionHierarchy<T>) -> Self {
+        let n = ch.n();
+        Self {
+            ch,
+            forward: H::new(),
+            backward: H::new(),
+            dist_f: vec![T::infinity(); n],
+            dist_b: vec![T::infinity(); n],
+            parent_f: vec![PARENT_NONE; n],
+            parent_b: vec![PARENT_NONE; n],
+            touched: Vec::new(),
+        }
+    }
+
+    /// Shortest source -> target path, or None if unreachable.
+    pub fn query(&mut self, source: usize, target: usize) -> Option<ChPath<T>> {
+        debug_assert!(source < self.ch.n(), "Source vertex out of bounds");
+        debug_assert!(target < self.ch.n(), "Target vertex out of bounds");
+
+        self.reset();
+        self.dist_f[source] = T::zero();
+        self.dist_b[target] = T::zero();
+        self.touched.extend([source, target]);
+        self.forward.push(T::zero(), source);
+        self.backward.push(T::zero(), target);
+
+        let mut mu = T::infinity();
+        let mut meet = PARENT_NONE;
+        let (mut done_f, mut done_b) = (false, false);
+        let mut forward_turn = true;
+
+        while !(done_f && done_b) {
+            let is_forward = !done_f && (forward_turn || done_b);
+            forward_turn = !forward_turn;
+
+            let (heap, dist, parent, other, edges) = if is_forward {
+                (
+                    &mut self.forward,
+                    &mut self.dist_f,
+                    &mut self.parent_f,
+                    &self.dist_b,
+                    &self.ch.up,
+                )
+            } else {
+                (
+                    &mut self.backward,
+                    &mut self.dist_b,
+                    &mut self.parent_b,
+                    &self.dist_f,
+                    &self.ch.down,
+                )
+            };
+
+            // A side is finished once its smallest key can't beat mu
+            let Some(entry) = heap.pop() else {
+                *if is_forward { &mut done_f } else { &mut done_b } = true;
+                continue;
+            };
+            let (u, d_u) = (entry.vertex, entry.dist);
+            if d_u > dist[u] {
+                continue;
+            }
+            if d_u >= mu {
+                *if is_forward { &mut done_f } else { &mut done_b } = true;
+                continue;
+            }
+
+            if d_u + other[u] < mu {
+                mu = d_u + other[u];
+                meet = u;
+            }
+
+            relax_upward(&edges[u], u, d_u, dist, parent, heap, &mut self.touched);
+        }
+
+        if meet == PARENT_NONE {
+            return None;
+        }
+        Some(ChPath {
+            distance: mu,
+            path: self.unpack(meet),
+        })
+    }
+
+    fn reset(&mut self) {
+        for &v in &self.touched {
+            self.dist_f[v] = T::infinity();
+            self.dist_b[v] = T::infinity();
+            self.parent_f[v] = PARENT_NONE;
+            self.parent_b[v] = PARENT_NONE;
+        }
+        self.touched.clear();
+        self.forward.clear();
+        self.backward.clear();
+    }
+
+    /// Hierarchy path s .. meet .. t, with every shortcut expanded.
+    fn unpack(&self, meet: usize) -> Vec<usize> {
+        let mut ch_path = Vec::new();
+        let mut x = meet;
+        while x != PARENT_NONE {
+            ch_path.push(x);
+            x = self.parent_f[x];
+        }
+        ch_path.reverse();
+        let mut x = self.parent_b[meet];
+        while x != PARENT_NONE {
+            ch_path.push(x);
+            x = self.parent_b[x];
+        }
+
+        let mut path = vec![ch_path[0]];
+        for pair in ch_path.windows(2) {
+            self.ch.unpack_into(pair[0], pair[1], &mut path);
+        }
+        path
+    }
+}
+
+#[inline]
+fn relax_upward<T: FloatNumber, H: PriorityQueue<T>>(
+    edges: &[ChEdge<T>],
+    u: usize,
+    d_u: T,
+    dist: &mut [T],
+    parent: &mut [usize],
+    heap: &mut H,
+    touched: &mut Vec<usize>,
+) {
+    for e in edges {
+        let new_dist = d_u + e.w;
+        if new_dist < dist[e.other] {
+            dist[e.other] = new_dist;
+            parent[e.other] = u;
+            heap.decrease_key(new_dist, e.other);
+            touched.push(e.other);
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use crate::algorithms::HasSsspConfig;
+use crate::algorithms::{finalize_sssp, init_sssp, SsspAlgorithm, SsspAlgorithmInfo, SsspResult};
+use crate::utils::{FloatNumber, Graph, SsspBuffers};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim};
+use num_traits::Float;
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+
+use super::config::{Delta, DeltaSteppingConfig};
+
+/// Relax request (target vertex, new distance, parent).
+#[derive(Clone, Copy)]
+struct Request<T: FloatNumber> {
+    target: usize,
+    dist: T,
+    parent: usize,
+}
+
+#[derive(Debug)]
+pub struct DeltaStepping<T: FloatNumber> {
+    config: DeltaSteppingConfig,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: FloatNumber> DeltaStepping<T> {
+    pub fn new() -> Self {
+        Self {
+            config: DeltaSteppingConfig::default(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    pub fn with_config(config: DeltaSteppingConfig) -> Self {
+        Self {
+            config,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    pub fn config(&self) -> &DeltaSteppingConfig {
+        &self.config
+    }
+
+    pub fn config_mut(&mut self) -> &mut DeltaSteppingConfig {
+        &mut self.config
+    }
+}
+
+impl<T: FloatNumber> Default for DeltaStepping<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FloatNumber> SsspAlgorithmInfo for DeltaStepping<T> {
+    fn name(&self) -> &'static str {
+        "Delta-Stepping"
+    }
+
+    fn supports_negative_weights(&self) -> bool {
+        false
+    }
+
+    fn target(&self) -> Option<usize> {
+        self.config.sssp_config().early_stop
+    }
+}
+
+impl<T, N, G> SsspAlgorithm<T, N, G> for DeltaStepping<T>
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T> + Sync,
+    DefaultAllocator: Allocator<N>,
+{
+    fn run(&mut self, graph: &G, source: usize, buffers: &mut SsspBuffers<T, N>) -> SsspResult<T> {
+        debug_assert!(source < graph.n(), "Source vertex out of bounds");
+
+        init_sssp(buffers, source);
+
+        let delta = resolve_delta(graph, self.config.delta);
+        let bucket_of = |d: T| Float::floor(d / delta).to_usize().unwrap_or(usize::MAX);
+
+        let mut buckets: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        buckets.insert(0, vec![source]);
+
+        let mut iterations = 0usize;
+
+        while let Some((i, mut bucket)) = buckets.pop_first() {
+            let mut settled = Vec::new();
+
+            // Light edges may refill bucket i, so repeat until it drains
+            loop {
+                bucket.retain(|&v| bucket_of(buffers.dist[v]) == i);
+                bucket.sort_unstable();
+                bucket.dedup();
+                if bucket.is_empty() {
+                    break;
+                }
+
+                iterations += 1;
+                let requests = collect_requests(graph, buffers, &bucket, delta, true);
+                settled.append(&mut bucket);
+
+                for v in apply_requests(buffers, &requests) {
+                    let b = bucket_of(buffers.dist[v]);
+                    if b == i {
+                        bucket.push(v);
+                    } else {
+                        buckets.entry(b).or_default().push(v);
+                    }
+                }
+            }
+
+            settled.sort_unstable();
+            settled.dedup();
+            if settled.iter().any(|&v| self.config.should_stop(v)) {
+                break;
+            }
+
+            // Heavy edges always land in later buckets: one pass suffices
+            iterations += 1;
+            let requests = collect_requests(graph, buffers, &settled, delta, false);
+            for v in apply_requests(buffers, &requests) {
+                buckets
+                    .entry(bucket_of(buffers.dist[v]))
+                    .or_default()
+                    .push(v);
+            }
+        }
+
+        finalize_sssp(buffers, iterations, false)
+    }
+}
+
+/// Bucket width from config, or average edge weight.
+fn resolve_delta<T, G>(graph: &G, delta: Delta) -> T
+where
+    T: FloatNumber,
+    G: Graph<T> + Sync,
+{
+    let width = match delta {
+        Delta::Fixed(d) => T::from(d).unwrap(),
+        Delta::Auto => {
+            let (sum, count) = (0..graph.n())
+                .into_par_iter()
+                .map(|u| {
+                    let mut sum = T::zero();
+                    let mut count = 0usize;
+                    graph.for_each_out_edge(u, |_, w| {
+                        sum += w;
+                        count += 1;
+                    });
+                    (sum, count)
+                })
+                .reduce(|| (T::zero(), 0), |a, b| (a.0 + b.0, a.1 + b.1));
+
+            if count == 0 {
+                T::one()
+            } else {
+                sum / T::from(count).unwrap()
+            }
+        }
+    };
+
+    // Zero-weight graphs would make every bucket index infinite
+    if width > T::zero() && !width.is_infinite() {
+        width
+    } else {
+        T::one()
+    }
+}
+
+/// Collect relax requests for light (w <= delta) or heavy out-edges of `frontier`.
+fn collect_requests<T, N, G>(
+    graph: &G,
+    buffers: &SsspBuffers<T, N>,
+    frontier: &[usize],
+    delta: T,
+    light: bool,
+) -> Vec<Request<T>>
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T> + Sync,
+    DefaultAllocator: Allocator<N>,
+{
+    let dist_slice = buffers.dist.as_slice();
+
+    frontier
+        .par_iter()
+        .flat_map(|&u| {
+            let d_u = dist_slice[u];
+            let mut local_requests = Vec::new();
+            graph.for_each_out_edge(u, |v, w| {
+                debug_assert!(
+                    w >= T::zero(),
+                    "Delta-stepping requires non-negative weights"
+                );
+
+                if (w <= delta) != light {
+                    return;
+                }
+                let new_dist = d_u + w;
+                if new_dist < dist_slice[v] {
+                    local_requests.push(Request {
+                        target: v,
+                        dist: new_dist,
+                        parent: u,
+                    });
+                }
+            });
+            local_requests
+        })
+        .collect()
+}
+
+/// Apply requests in order, keeping the min per target. Returns improved vertices.
+fn apply_requests<T, N>(buffers: &mut SsspBuffers<T, N>, requests: &[Request<T>]) -> Vec<usize>
+where
+    T: FloatNumber,
+    N: Dim,
+    DefaultAllocator: Allocator<N>,
+{
+    let mut improved = Vec::new();
+    for req in requests {
+        if req.dist < buffers.dist[req.target] {
+            buffers.dist[req.target] = req.dist;
+            buffers.parent[req.target] = req.parent;
+            improved.push(req.target);
+        }
+    }
+    improved
+}
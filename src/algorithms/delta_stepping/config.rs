@@ -0,0 +1,44 @@
+use crate::algorithms::{HasSsspConfig, SsspConfig};
+
+/// Bucket width selection.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Delta {
+    Fixed(f64),
+    Auto, // Average edge weight
+}
+
+#[derive(Clone, Debug)]
+pub struct DeltaSteppingConfig {
+    base: SsspConfig,
+    pub delta: Delta,
+}
+
+impl Default for DeltaSteppingConfig {
+    fn default() -> Self {
+        Self {
+            base: SsspConfig::default(),
+            delta: Delta::Auto,
+        }
+    }
+}
+
+impl DeltaSteppingConfig {
+    pub fn with_target(target: usize) -> Self {
+        Self {
+            base: SsspConfig::with_target(target),
+            delta: Delta::Auto,
+        }
+    }
+
+    pub fn with_delta(mut self, delta: f64) -> Self {
+        debug_assert!(delta > 0.0, "Delta must be positive");
+        self.delta = Delta::Fixed(delta);
+        self
+    }
+}
+
+impl HasSsspConfig for DeltaSteppingConfig {
+    fn sssp_config(&self) -> &SsspConfig {
+        &self.base
+    }
+}
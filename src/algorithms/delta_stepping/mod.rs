@@ -0,0 +1,40 @@
+mod algo;
+mod config;
+
+pub use algo::DeltaStepping;
+pub use config::{Delta, DeltaSteppingConfig};
+
+use crate::algorithms::{SsspAlgorithm, SsspResult};
+use crate::utils::{FloatNumber, Graph, SsspBuffers};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim};
+
+/// One-shot delta-stepping execute (auto delta).
+pub fn cheeky_delta_stepping<T, N, G>(
+    graph: &G,
+    source: usize,
+    buffers: &mut SsspBuffers<T, N>,
+) -> SsspResult<T>
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T> + Sync,
+    DefaultAllocator: Allocator<N>,
+{
+    DeltaStepping::<T>::new().run(graph, source, buffers)
+}
+
+pub fn delta_stepping_to<T, N, G>(
+    graph: &G,
+    source: usize,
+    target: usize,
+    buffers: &mut SsspBuffers<T, N>,
+) -> SsspResult<T>
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T> + Sync,
+    DefaultAllocator: Allocator<N>,
+{
+    DeltaStepping::<T>::with_config(DeltaSteppingConfig::with_target(target))
+        .run(graph, source, buffers)
+}
@@ -39,6 +39,15 @@ impl<T: FloatNumber, H: PriorityQueue<T>> Dijkstra<T, H> {
         }
     }
 
+    /// Use a pre-built queue, e.g. `BucketQueue::with_max_weight(100.0, 0.5)`.
+    pub fn with_heap(heap: H) -> Self {
+        Self {
+            config: DijkstraConfig::default(),
+            heap,
+            _phantom: PhantomData,
+        }
+    }
+
     pub fn config(&self) -> &DijkstraConfig {
         &self.config
     }
@@ -62,6 +71,10 @@ impl<T: FloatNumber, H: PriorityQueue<T>> SsspAlgorithmInfo for Dijkstra<T, H> {
     fn supports_negative_weights(&self) -> bool {
         false
     }
+
+    fn target(&self) -> Option<usize> {
+        self.config.sssp_config().early_stop
+    }
 }
 
 impl<T, N, G, H> SsspAlgorithm<T, N, G> for Dijkstra<T, H>
@@ -85,7 +98,8 @@ where
             let u = entry.vertex;
             let d_u = entry.dist;
 
-            if self.config.lazy_deletion && d_u > buffers.dist[u] {
+            let skip_stale = self.config.lazy_deletion || !H::DECREASE_KEY;
+            if skip_stale && d_u > buffers.dist[u] {
                 continue;
             }
 
@@ -106,7 +120,7 @@ where
                     v,
                     w,
                 ) {
-                    self.heap.push(buffers.dist[v], v);
+                    self.heap.decrease_key(buffers.dist[v], v);
                 }
             });
         }
@@ -3,7 +3,7 @@ use crate::algorithms::{HasSsspConfig, SsspConfig};
 #[derive(Clone, Debug)]
 pub struct DijkstraConfig {
     base: SsspConfig,
-    pub lazy_deletion: bool, // Skip stale entries of heap
+    pub lazy_deletion: bool, // Skip stale heap entries (forced on unless H::DECREASE_KEY)
 }
 
 impl Default for DijkstraConfig {
@@ -0,0 +1,91 @@
+use crate::utils::{FloatNumber, Graph};
+use std::fmt;
+
+/// Invalid input to a shortest-path or spanning-tree run, reported by `try_run`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SsspError {
+    SourceOutOfBounds { source: usize, n: usize },
+    TargetOutOfBounds { target: usize, n: usize },
+    BufferSizeMismatch { expected: usize, actual: usize },
+    MatrixTooLarge { n: usize }, // APSP: n * n overflows usize
+    NegativeWeight { from: usize, to: usize }, // Algorithm needs w >= 0
+    NanWeight { from: usize, to: usize },
+    MissingTarget, // A* needs one
+}
+
+impl fmt::Display for SsspError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SourceOutOfBounds { source, n } => {
+                write!(f, "source vertex {source} out of bounds for {n} vertices")
+            }
+            Self::TargetOutOfBounds { target, n } => {
+                write!(f, "target vertex {target} out of bounds for {n} vertices")
+            }
+            Self::BufferSizeMismatch { expected, actual } => {
+                write!(f, "buffer has {actual} entries, expected {expected}")
+            }
+            Self::MatrixTooLarge { n } => {
+                write!(f, "{n} x {n} distance matrix does not fit in memory")
+            }
+            Self::NegativeWeight { from, to } => {
+                write!(f, "negative weight on edge {from} -> {to}")
+            }
+            Self::NanWeight { from, to } => write!(f, "NaN weight on edge {from} -> {to}"),
+            Self::MissingTarget => f.write_str("algorithm requires a target vertex"),
+        }
+    }
+}
+
+impl std::error::Error for SsspError {}
+
+#[inline]
+pub(crate) fn check_source(source: usize, n: usize) -> Result<(), SsspError> {
+    if source < n {
+        Ok(())
+    } else {
+        Err(SsspError::SourceOutOfBounds { source, n })
+    }
+}
+
+#[inline]
+pub(crate) fn check_target(target: usize, n: usize) -> Result<(), SsspError> {
+    if target < n {
+        Ok(())
+    } else {
+        Err(SsspError::TargetOutOfBounds { target, n })
+    }
+}
+
+#[inline]
+pub(crate) fn check_buffer_len(expected: usize, actual: usize) -> Result<(), SsspError> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(SsspError::BufferSizeMismatch { expected, actual })
+    }
+}
+
+/// One pass over every edge: NaN always fails, negatives unless allowed.
+pub(crate) fn check_weights<T: FloatNumber, G: Graph<T>>(
+    graph: &G,
+    allow_negative: bool,
+) -> Result<(), SsspError> {
+    for u in 0..graph.n() {
+        let mut bad = None;
+        graph.for_each_out_edge(u, |v, w| {
+            if bad.is_some() {
+                return;
+            }
+            if w.is_nan() {
+                bad = Some(SsspError::NanWeight { from: u, to: v });
+            } else if !allow_negative && w < T::zero() {
+                bad = Some(SsspError::NegativeWeight { from: u, to: v });
+            }
+        });
+        if let Some(err) = bad {
+            return Err(err);
+        }
+    }
+    Ok(())
+}
@@ -1,5 +1,7 @@
-use crate::algorithms::{ApspAlgorithm, ApspAlgorithmInfo, ApspResult};
-use crate::utils::{ApspBuffers, FloatNumber, Graph};
+use crate::algorithms::{
+    finalize_apsp, ApspAlgorithm, ApspAlgorithmInfo, ApspResult, NegativeCycle,
+};
+use crate::utils::{ApspBuffers, FloatNumber, Graph, APSP_NO_PATH};
 use rayon::prelude::*;
 
 use super::config::FloydWarshallConfig;
@@ -70,7 +72,11 @@ where
             false
         };
 
-        finalize_apsp(buffers, n, negative_cycle)
+        let mut result = finalize_apsp(buffers, n, negative_cycle);
+        if negative_cycle {
+            result.cycle = extract_cycle(graph, buffers);
+        }
+        result
     }
 }
 
@@ -140,21 +146,38 @@ fn update_for_k<T: FloatNumber>(buffers: &mut ApspBuffers<T>, k: usize) {
     }
 }
 
-fn finalize_apsp<T: FloatNumber>(
-    buffers: &ApspBuffers<T>,
-    n: usize,
-    negative_cycle: bool,
-) -> ApspResult<T> {
-    let mut pairs_reached = 0usize;
+/// Follow next[x][i] from any i with dist[i][i] < 0 until a vertex repeats.
+/// next always names a real out-edge, so the loop found is a graph cycle;
+/// keep the first one that is negative.
+fn extract_cycle<T, G>(graph: &G, buffers: &ApspBuffers<T>) -> Option<NegativeCycle<T>>
+where
+    T: FloatNumber,
+    G: Graph<T>,
+{
+    let n = buffers.n;
+    let mut pos = vec![usize::MAX; n];
+
+    for i in (0..n).filter(|&i| buffers.get(i, i) < T::zero()) {
+        let mut walk = Vec::new();
+        let mut x = i;
+        while x != APSP_NO_PATH && pos[x] == usize::MAX {
+            pos[x] = walk.len();
+            walk.push(x);
+            x = buffers.get_next(x, i);
+        }
+
+        let cycle = (x != APSP_NO_PATH).then(|| walk[pos[x]..].to_vec());
+        for &y in &walk {
+            pos[y] = usize::MAX;
+        }
 
-    // Count reachable pairs
-    for i in 0..n {
-        for j in 0..n {
-            if !buffers.get(i, j).is_infinite() {
-                pairs_reached += 1;
+        if let Some(vertices) = cycle {
+            let cycle = NegativeCycle::from_vertices(graph, vertices);
+            if cycle.weight < T::zero() {
+                return Some(cycle);
             }
         }
     }
 
-    ApspResult::new(n, negative_cycle, pairs_reached)
+    None
 }
@@ -9,7 +9,7 @@ struct MinHeapEntry<T: FloatNumber>(HeapEntry<T>);
 
 impl<T: FloatNumber> PartialEq for MinHeapEntry<T> {
     fn eq(&self, other: &Self) -> bool {
-        self.0.dist == other.0.dist && self.0.vertex == other.0.vertex
+        self.0 == other.0
     }
 }
 
@@ -21,12 +21,10 @@ impl<T: FloatNumber> PartialOrd for MinHeapEntry<T> {
     }
 }
 
+// Reversed: std's max-heap pops the smallest (dist, vertex)
 impl<T: FloatNumber> Ord for MinHeapEntry<T> {
     fn cmp(&self, other: &Self) -> Ordering {
-        match other.0.dist.partial_cmp(&self.0.dist) {
-            Some(ord) => ord,
-            None => Ordering::Equal,
-        }
+        other.0.cmp(&self.0)
     }
 }
 
@@ -0,0 +1,137 @@
+use crate::utils::FloatNumber;
+use num_traits::Float;
+
+use super::traits::{HeapEntry, PriorityQueue};
+
+const DEFAULT_MAX_WEIGHT: f64 = 1024.0;
+
+/// Dial's bucket queue: a circular array of `ceil(C / width) + 1` buckets,
+/// where C is the largest key jump (max edge weight for Dijkstra). Bucket
+/// number k holds keys in [k * width, (k + 1) * width) and lives at slot
+/// `k % len`. Memory is O(C / width) buckets plus the entries, independent of
+/// the largest distance.
+///
+/// Keys must never fall below the current bucket (true for Dijkstra) and must
+/// stay within C of it; a key beyond that span panics rather than growing.
+///
+/// Pops within a bucket are unordered. Dijkstra's popped distances are still
+/// final for integer keys with width 1, or when width is at most the smallest
+/// edge weight (all positive). Otherwise Dijkstra still converges by re-scanning
+/// vertices, but early stop may fire before the target is final.
+#[derive(Clone, Debug)]
+pub struct BucketQueue<T: FloatNumber> {
+    width: T,
+    buckets: Vec<Vec<HeapEntry<T>>>,
+    current: usize, // Bucket number of the last pop
+    len: usize,
+}
+
+impl<T: FloatNumber> BucketQueue<T> {
+    /// Width 1, for integer weights up to 1024.
+    pub fn new() -> Self {
+        Self::with_max_weight(T::from(DEFAULT_MAX_WEIGHT).unwrap(), T::one())
+    }
+
+    /// Sized for key jumps up to `max_weight`, in buckets of `width`.
+    pub fn with_max_weight(max_weight: T, width: T) -> Self {
+        assert!(width > T::zero(), "Bucket width must be positive");
+        assert!(
+            max_weight >= T::zero() && !max_weight.is_infinite(),
+            "Max weight must be finite and non-negative"
+        );
+        let count = Float::ceil(max_weight / width)
+            .to_usize()
+            .and_then(|c| c.checked_add(1))
+            .expect("Too many buckets for max_weight / width");
+        Self {
+            width,
+            buckets: vec![Vec::new(); count],
+            current: 0,
+            len: 0,
+        }
+    }
+
+    pub fn width(&self) -> T {
+        self.width
+    }
+
+    /// Number of buckets in the circular array.
+    pub fn num_buckets(&self) -> usize {
+        self.buckets.len()
+    }
+
+    #[inline]
+    fn bucket_of(&self, dist: T) -> usize {
+        debug_assert!(
+            dist >= T::zero() && !dist.is_infinite(),
+            "BucketQueue keys must be finite and non-negative"
+        );
+        Float::floor(dist / self.width)
+            .to_usize()
+            .expect("BucketQueue key too large")
+    }
+}
+
+impl<T: FloatNumber> Default for BucketQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FloatNumber> PriorityQueue<T> for BucketQueue<T> {
+    fn new() -> Self {
+        BucketQueue::new()
+    }
+
+    fn with_capacity(_capacity: usize) -> Self {
+        BucketQueue::new()
+    }
+
+    #[inline]
+    fn push(&mut self, dist: T, vertex: usize) {
+        let k = self.bucket_of(dist);
+        debug_assert!(k >= self.current, "BucketQueue key below current bucket");
+        let k = k.max(self.current);
+        assert!(
+            k - self.current < self.buckets.len(),
+            "BucketQueue key beyond max_weight of the current bucket"
+        );
+        let slot = k % self.buckets.len();
+        self.buckets[slot].push(HeapEntry::new(dist, vertex));
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<HeapEntry<T>> {
+        if self.len == 0 {
+            return None;
+        }
+        // Every key is within the ring, so this scans fewer than len slots
+        let len = self.buckets.len();
+        while self.buckets[self.current % len].is_empty() {
+            self.current += 1;
+        }
+        self.len -= 1;
+        self.buckets[self.current % len].pop()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    /// O(buckets) when non-empty, keeps the bucket allocations.
+    fn clear(&mut self) {
+        if self.len > 0 {
+            for bucket in &mut self.buckets {
+                bucket.clear();
+            }
+        }
+        self.current = 0;
+        self.len = 0;
+    }
+}
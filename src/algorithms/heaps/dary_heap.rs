@@ -0,0 +1,189 @@
+use crate::utils::FloatNumber;
+
+use super::traits::{AddressablePriorityQueue, HeapEntry, PriorityQueue};
+
+const NOT_IN_HEAP: usize = usize::MAX;
+
+/// Indexed d-ary heap: each node has `D` children, so the tree is shallower
+/// and a sift-down scans `D` adjacent entries. `D = 4` is a good default for
+/// road graphs. At most one entry per vertex, in-place decrease-key.
+#[derive(Clone, Debug)]
+pub struct DaryHeap<T: FloatNumber, const D: usize = 4> {
+    heap: Vec<HeapEntry<T>>,
+    pos: Vec<usize>, // pos[v] = index of v in heap, grown on demand
+}
+
+impl<T: FloatNumber, const D: usize> DaryHeap<T, D> {
+    const ARITY_OK: () = assert!(D >= 2, "DaryHeap arity must be at least 2");
+
+    pub fn new() -> Self {
+        let () = Self::ARITY_OK;
+        Self {
+            heap: Vec::new(),
+            pos: Vec::new(),
+        }
+    }
+
+    /// `capacity` = number of vertex ids expected.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut heap = Self::new();
+        heap.heap.reserve(capacity);
+        heap.pos.resize(capacity, NOT_IN_HEAP);
+        heap
+    }
+
+    pub const fn arity(&self) -> usize {
+        D
+    }
+
+    /// Smallest entry without removing it.
+    pub fn peek(&self) -> Option<HeapEntry<T>> {
+        self.heap.first().copied()
+    }
+
+    #[inline]
+    fn index_of(&self, vertex: usize) -> Option<usize> {
+        self.pos.get(vertex).copied().filter(|&i| i != NOT_IN_HEAP)
+    }
+
+    fn insert(&mut self, dist: T, vertex: usize) {
+        if vertex >= self.pos.len() {
+            self.pos.resize(vertex + 1, NOT_IN_HEAP);
+        }
+        let i = self.heap.len();
+        self.heap.push(HeapEntry::new(dist, vertex));
+        self.pos[vertex] = i;
+        self.sift_up(i);
+    }
+
+    // Sifts move a hole instead of swapping, writing `pos` once per level
+    fn sift_up(&mut self, mut i: usize) {
+        let entry = self.heap[i];
+        while i > 0 {
+            let parent = (i - 1) / D;
+            if entry >= self.heap[parent] {
+                break;
+            }
+            self.heap[i] = self.heap[parent];
+            self.pos[self.heap[i].vertex] = i;
+            i = parent;
+        }
+        self.heap[i] = entry;
+        self.pos[entry.vertex] = i;
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        let len = self.heap.len();
+        let entry = self.heap[i];
+        loop {
+            let first = D * i + 1;
+            if first >= len {
+                break;
+            }
+            let last = (first + D).min(len);
+            let mut child = first;
+            for c in first + 1..last {
+                if self.heap[c] < self.heap[child] {
+                    child = c;
+                }
+            }
+            if self.heap[child] >= entry {
+                break;
+            }
+            self.heap[i] = self.heap[child];
+            self.pos[self.heap[i].vertex] = i;
+            i = child;
+        }
+        self.heap[i] = entry;
+        self.pos[entry.vertex] = i;
+    }
+}
+
+impl<T: FloatNumber, const D: usize> Default for DaryHeap<T, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FloatNumber, const D: usize> PriorityQueue<T> for DaryHeap<T, D> {
+    const DECREASE_KEY: bool = true;
+
+    fn new() -> Self {
+        DaryHeap::new()
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        DaryHeap::with_capacity(capacity)
+    }
+
+    /// Insert, or move an existing vertex to the new key (either direction).
+    fn push(&mut self, dist: T, vertex: usize) {
+        match self.index_of(vertex) {
+            None => self.insert(dist, vertex),
+            Some(i) => {
+                let old = self.heap[i].dist;
+                self.heap[i].dist = dist;
+                if HeapEntry::cmp_dist(dist, old).is_lt() {
+                    self.sift_up(i);
+                } else {
+                    self.sift_down(i);
+                }
+            }
+        }
+    }
+
+    fn pop(&mut self) -> Option<HeapEntry<T>> {
+        let last = self.heap.pop()?;
+        let top = if self.heap.is_empty() {
+            last
+        } else {
+            let top = std::mem::replace(&mut self.heap[0], last);
+            self.sift_down(0);
+            top
+        };
+        self.pos[top.vertex] = NOT_IN_HEAP;
+        Some(top)
+    }
+
+    /// Insert, or lower the key of a queued vertex; larger keys are ignored.
+    fn decrease_key(&mut self, dist: T, vertex: usize) {
+        match self.index_of(vertex) {
+            None => self.insert(dist, vertex),
+            Some(i) if HeapEntry::cmp_dist(dist, self.heap[i].dist).is_lt() => {
+                self.heap[i].dist = dist;
+                self.sift_up(i);
+            }
+            Some(_) => {}
+        }
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// O(len), keeps the position table allocated.
+    fn clear(&mut self) {
+        for e in &self.heap {
+            self.pos[e.vertex] = NOT_IN_HEAP;
+        }
+        self.heap.clear();
+    }
+}
+
+impl<T: FloatNumber, const D: usize> AddressablePriorityQueue<T> for DaryHeap<T, D> {
+    #[inline]
+    fn contains(&self, vertex: usize) -> bool {
+        self.index_of(vertex).is_some()
+    }
+
+    #[inline]
+    fn priority(&self, vertex: usize) -> Option<T> {
+        self.index_of(vertex).map(|i| self.heap[i].dist)
+    }
+}
@@ -1,113 +1,385 @@
 use crate::utils::FloatNumber;
-use std::mem;
 
-use super::traits::{HeapEntry, PriorityQueue};
+use super::traits::{AddressablePriorityQueue, HeapEntry, PriorityQueue};
 
-#[derive(Debug)]
+const NIL: usize = usize::MAX;
+
+/// Stable reference to a heap entry, for `decrease` and `get`.
+/// Goes stale once the entry is popped or the heap is cleared.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FibHandle {
+    index: usize,
+    generation: u32,
+}
+
+#[derive(Clone, Debug)]
 struct FibNode<T: FloatNumber> {
     entry: HeapEntry<T>,
+    parent: usize,
+    child: usize, // Any one child; children form a circular list
+    left: usize,
+    right: usize,
     degree: usize,
-    child: Option<Box<FibNode<T>>>,
-    sibling: Option<Box<FibNode<T>>>,
+    marked: bool, // Lost a child since becoming a child itself
+    live: bool,
+    generation: u32, // Bumped on free, invalidates old handles
 }
 
-impl<T: FloatNumber> FibNode<T> {
-    fn new(dist: T, vertex: usize) -> Self {
-        Self {
-            entry: HeapEntry::new(dist, vertex),
-            degree: 0,
-            child: None,
-            sibling: None,
-        }
-    }
-
-    fn link_child(&mut self, mut other: Box<FibNode<T>>) {
-        other.sibling = self.child.take();
-        self.child = Some(other);
-        self.degree += 1;
-    }
-}
-
-#[derive(Debug)]
+/// Fibonacci heap on an index arena: O(1) amortized push and decrease-key,
+/// O(log n) amortized pop. Freed slots are reused.
+#[derive(Clone, Debug)]
 pub struct FibonacciHeap<T: FloatNumber> {
-    roots: Option<Box<FibNode<T>>>,
-    min_node: Option<*mut FibNode<T>>,
+    nodes: Vec<FibNode<T>>,
+    free: Vec<usize>,
+    min: usize,
     len: usize,
+    by_vertex: Vec<usize>, // Tracked entry per vertex, for the PriorityQueue API
+    roots: Vec<usize>,     // Consolidation scratch
+    degrees: Vec<usize>,   // Consolidation scratch
 }
 
 impl<T: FloatNumber> FibonacciHeap<T> {
     pub fn new() -> Self {
         Self {
-            roots: None,
-            min_node: None,
+            nodes: Vec::new(),
+            free: Vec::new(),
+            min: NIL,
             len: 0,
+            by_vertex: Vec::new(),
+            roots: Vec::new(),
+            degrees: Vec::new(),
         }
     }
 
-    pub fn with_capacity(_capacity: usize) -> Self {
-        Self::new()
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            nodes: Vec::with_capacity(capacity),
+            by_vertex: vec![NIL; capacity],
+            ..Self::new()
+        }
+    }
+
+    /// Add an entry, even if `vertex` is already queued.
+    pub fn insert(&mut self, dist: T, vertex: usize) -> FibHandle {
+        let x = self.alloc(HeapEntry::new(dist, vertex));
+        self.add_root(x);
+        self.len += 1;
+        self.track(x);
+        FibHandle {
+            index: x,
+            generation: self.nodes[x].generation,
+        }
+    }
+
+    /// Entry behind `handle`, or None if it was popped.
+    pub fn get(&self, handle: FibHandle) -> Option<HeapEntry<T>> {
+        self.resolve(handle).map(|x| self.nodes[x].entry)
+    }
+
+    /// Lower the key behind `handle`; larger keys are ignored.
+    /// Panics if the handle is stale.
+    pub fn decrease(&mut self, handle: FibHandle, dist: T) {
+        let x = self.resolve(handle).expect("Stale Fibonacci heap handle");
+        if self.lowers(x, dist) {
+            self.decrease_node(x, dist);
+        }
+    }
+
+    /// Smallest entry without removing it.
+    pub fn peek(&self) -> Option<HeapEntry<T>> {
+        (self.min != NIL).then(|| self.nodes[self.min].entry)
+    }
+
+    /// Move every entry of `other` into this heap in O(|other| arena).
+    /// Handles into `other` become stale. A vertex queued in both keeps its
+    /// smaller entry tracked for `decrease_key`.
+    pub fn meld(&mut self, other: FibonacciHeap<T>) {
+        let offset = self.nodes.len();
+        let shift = |i: usize| if i == NIL { NIL } else { i + offset };
+
+        for mut node in other.nodes {
+            node.parent = shift(node.parent);
+            node.child = shift(node.child);
+            node.left = shift(node.left);
+            node.right = shift(node.right);
+            self.nodes.push(node);
+        }
+        self.free.extend(other.free.iter().map(|&i| i + offset));
+
+        let other_min = shift(other.min);
+        if other_min != NIL {
+            if self.min == NIL {
+                self.min = other_min;
+            } else {
+                // Splice the two circular root lists
+                let (a, b) = (self.min, other_min);
+                let (a_right, b_left) = (self.nodes[a].right, self.nodes[b].left);
+                self.nodes[a].right = b;
+                self.nodes[b].left = a;
+                self.nodes[b_left].right = a_right;
+                self.nodes[a_right].left = b_left;
+                if self.less(b, a) {
+                    self.min = b;
+                }
+            }
+        }
+        self.len += other.len;
+
+        for x in offset..self.nodes.len() {
+            if !self.nodes[x].live {
+                continue;
+            }
+            let v = self.nodes[x].entry.vertex;
+            match self.tracked(v) {
+                Some(y) if !self.less(x, y) => {}
+                _ => self.track(x),
+            }
+        }
     }
 
-    fn add_to_roots(&mut self, mut node: Box<FibNode<T>>) {
-        let node_ptr = node.as_mut() as *mut FibNode<T>;
+    /// Heap order of nodes x and y: (dist, vertex), NaN last.
+    #[inline]
+    fn less(&self, x: usize, y: usize) -> bool {
+        self.nodes[x].entry < self.nodes[y].entry
+    }
 
-        match self.min_node {
+    /// Whether `dist` is a strictly lower key for node x.
+    #[inline]
+    fn lowers(&self, x: usize, dist: T) -> bool {
+        HeapEntry::cmp_dist(dist, self.nodes[x].entry.dist).is_lt()
+    }
+
+    fn alloc(&mut self, entry: HeapEntry<T>) -> usize {
+        let node = |generation| FibNode {
+            entry,
+            parent: NIL,
+            child: NIL,
+            left: NIL,
+            right: NIL,
+            degree: 0,
+            marked: false,
+            live: true,
+            generation,
+        };
+        let x = match self.free.pop() {
+            Some(x) => {
+                self.nodes[x] = node(self.nodes[x].generation);
+                x
+            }
             None => {
-                self.min_node = Some(node_ptr);
+                self.nodes.push(node(0));
+                self.nodes.len() - 1
             }
-            Some(min_ptr) => {
-                let min_dist = unsafe { (*min_ptr).entry.dist };
-                if node.entry.dist < min_dist {
-                    self.min_node = Some(node_ptr);
-                }
+        };
+        self.nodes[x].left = x;
+        self.nodes[x].right = x;
+        x
+    }
+
+    fn release(&mut self, x: usize) {
+        let v = self.nodes[x].entry.vertex;
+        if self.by_vertex.get(v) == Some(&x) {
+            self.by_vertex[v] = NIL;
+        }
+        let node = &mut self.nodes[x];
+        node.live = false;
+        node.generation = node.generation.wrapping_add(1);
+        self.free.push(x);
+    }
+
+    #[inline]
+    fn resolve(&self, handle: FibHandle) -> Option<usize> {
+        let node = self.nodes.get(handle.index)?;
+        (node.live && node.generation == handle.generation).then_some(handle.index)
+    }
+
+    #[inline]
+    fn tracked(&self, vertex: usize) -> Option<usize> {
+        self.by_vertex.get(vertex).copied().filter(|&x| x != NIL)
+    }
+
+    fn track(&mut self, x: usize) {
+        let v = self.nodes[x].entry.vertex;
+        if v >= self.by_vertex.len() {
+            self.by_vertex.resize(v + 1, NIL);
+        }
+        self.by_vertex[v] = x;
+    }
+
+    /// Remove x from its sibling list, leaving it a singleton.
+    fn unlink(&mut self, x: usize) {
+        let (l, r) = (self.nodes[x].left, self.nodes[x].right);
+        self.nodes[l].right = r;
+        self.nodes[r].left = l;
+        self.nodes[x].left = x;
+        self.nodes[x].right = x;
+    }
+
+    /// Insert singleton x right after a in a's list.
+    fn insert_after(&mut self, a: usize, x: usize) {
+        let r = self.nodes[a].right;
+        self.nodes[x].left = a;
+        self.nodes[x].right = r;
+        self.nodes[a].right = x;
+        self.nodes[r].left = x;
+    }
+
+    fn add_root(&mut self, x: usize) {
+        self.nodes[x].parent = NIL;
+        self.nodes[x].marked = false;
+        if self.min == NIL {
+            self.min = x;
+        } else {
+            self.insert_after(self.min, x);
+            if self.less(x, self.min) {
+                self.min = x;
             }
         }
+    }
 
-        node.sibling = self.roots.take();
-        self.roots = Some(node);
+    fn decrease_node(&mut self, x: usize, dist: T) {
+        self.nodes[x].entry.dist = dist;
+        let p = self.nodes[x].parent;
+        if p != NIL && self.less(x, p) {
+            self.cut(x, p);
+            self.cascading_cut(p);
+        }
+        if self.less(x, self.min) {
+            self.min = x;
+        }
+    }
 
-        if let Some(min_ptr) = self.min_node {
-            if min_ptr == node_ptr {
-                self.min_node = Some(self.roots.as_mut().unwrap().as_mut() as *mut FibNode<T>);
+    /// Move child x of p to the root list.
+    fn cut(&mut self, x: usize, p: usize) {
+        if self.nodes[x].right == x {
+            self.nodes[p].child = NIL;
+        } else {
+            if self.nodes[p].child == x {
+                self.nodes[p].child = self.nodes[x].right;
             }
+            self.unlink(x);
         }
+        self.nodes[p].degree -= 1;
+        self.add_root(x);
     }
 
-    fn consolidate(&mut self) {
-        if self.roots.is_none() {
-            return;
+    /// Cut marked ancestors until an unmarked one (which gets marked) or a root.
+    fn cascading_cut(&mut self, mut y: usize) {
+        loop {
+            let z = self.nodes[y].parent;
+            if z == NIL {
+                break;
+            }
+            if !self.nodes[y].marked {
+                self.nodes[y].marked = true;
+                break;
+            }
+            self.cut(y, z);
+            y = z;
         }
+    }
 
-        let max_degree = (self.len as f64).log2().ceil() as usize + 2;
-        let mut degree_table: Vec<Option<Box<FibNode<T>>>> =
-            (0..=max_degree).map(|_| None).collect();
+    /// Make root y a child of root x.
+    fn link(&mut self, y: usize, x: usize) {
+        self.unlink(y);
+        self.nodes[y].parent = x;
+        self.nodes[y].marked = false;
+        let c = self.nodes[x].child;
+        if c == NIL {
+            self.nodes[x].child = y;
+        } else {
+            self.insert_after(c, y);
+        }
+        self.nodes[x].degree += 1;
+    }
 
-        let mut current = self.roots.take();
-        while let Some(mut node) = current {
-            current = node.sibling.take();
-            let mut d = node.degree;
+    /// Link roots of equal degree until all degrees differ, then find the min.
+    fn consolidate(&mut self) {
+        let mut roots = std::mem::take(&mut self.roots);
+        roots.clear();
+        let start = self.min;
+        let mut x = start;
+        loop {
+            roots.push(x);
+            x = self.nodes[x].right;
+            if x == start {
+                break;
+            }
+        }
 
-            while d < degree_table.len() && degree_table[d].is_some() {
-                let mut other = degree_table[d].take().unwrap();
-                if other.entry.dist < node.entry.dist {
-                    mem::swap(&mut node, &mut other);
+        let mut degrees = std::mem::take(&mut self.degrees);
+        degrees.clear();
+        for &w in &roots {
+            let mut x = w;
+            let mut d = self.nodes[x].degree;
+            loop {
+                if d >= degrees.len() {
+                    degrees.resize(d + 1, NIL);
+                }
+                let y = degrees[d];
+                if y == NIL {
+                    break;
                 }
-                node.link_child(other);
+                let (top, below) = if self.less(y, x) { (y, x) } else { (x, y) };
+                self.link(below, top);
+                degrees[d] = NIL;
+                x = top;
                 d += 1;
             }
+            degrees[d] = x;
+        }
 
-            if d >= degree_table.len() {
-                degree_table.resize_with(d + 1, || None);
+        self.min = NIL;
+        for &x in degrees.iter().filter(|&&x| x != NIL) {
+            if self.min == NIL || self.less(x, self.min) {
+                self.min = x;
             }
-            degree_table[d] = Some(node);
         }
+        self.roots = roots;
+        self.degrees = degrees;
+    }
+
+    /// Delete any entry: cut it to the root list, force it to be the min, pop.
+    fn remove(&mut self, x: usize) -> HeapEntry<T> {
+        let p = self.nodes[x].parent;
+        if p != NIL {
+            self.cut(x, p);
+            self.cascading_cut(p);
+        }
+        self.min = x;
+        self.extract_min()
+    }
+
+    fn extract_min(&mut self) -> HeapEntry<T> {
+        let z = self.min;
 
-        self.roots = None;
-        self.min_node = None;
+        // Children become roots
+        let mut c = self.nodes[z].child;
+        while c != NIL {
+            let next = if self.nodes[c].right == c {
+                NIL
+            } else {
+                self.nodes[c].right
+            };
+            self.unlink(c);
+            self.add_root(c);
+            c = next;
+        }
+        self.nodes[z].child = NIL;
 
-        for node in degree_table.into_iter().flatten() {
-            self.add_to_roots(node);
+        let next = self.nodes[z].right;
+        self.unlink(z);
+        self.len -= 1;
+        if next == z {
+            self.min = NIL;
+        } else {
+            self.min = next;
+            self.consolidate();
         }
+
+        let entry = self.nodes[z].entry;
+        self.release(z);
+        entry
     }
 }
 
@@ -118,6 +390,8 @@ impl<T: FloatNumber> Default for FibonacciHeap<T> {
 }
 
 impl<T: FloatNumber> PriorityQueue<T> for FibonacciHeap<T> {
+    const DECREASE_KEY: bool = true;
+
     fn new() -> Self {
         FibonacciHeap::new()
     }
@@ -126,67 +400,38 @@ impl<T: FloatNumber> PriorityQueue<T> for FibonacciHeap<T> {
         FibonacciHeap::with_capacity(capacity)
     }
 
-    #[inline]
+    /// Insert, or move an existing vertex to the new key (raising = delete + insert).
     fn push(&mut self, dist: T, vertex: usize) {
-        let node = Box::new(FibNode::new(dist, vertex));
-        self.add_to_roots(node);
-        self.len += 1;
+        match self.tracked(vertex) {
+            None => {
+                self.insert(dist, vertex);
+            }
+            Some(x) if HeapEntry::cmp_dist(dist, self.nodes[x].entry.dist).is_le() => {
+                self.decrease_node(x, dist)
+            }
+            Some(x) => {
+                self.remove(x);
+                self.insert(dist, vertex);
+            }
+        }
     }
 
     fn pop(&mut self) -> Option<HeapEntry<T>> {
-        if self.len == 0 {
-            return None;
-        }
-
-        let mut prev: Option<*mut FibNode<T>> = None;
-        let mut current_ptr = self.roots.as_mut().map(|n| n.as_mut() as *mut FibNode<T>);
-        let mut min_prev: Option<*mut FibNode<T>> = None;
-        let min_ptr = self.min_node?;
+        (self.min != NIL).then(|| self.extract_min())
+    }
 
-        while let Some(curr) = current_ptr {
-            if curr == min_ptr {
-                min_prev = prev;
-                break;
+    /// Insert, or lower the key of a queued vertex in O(1) amortized.
+    fn decrease_key(&mut self, dist: T, vertex: usize) {
+        match self.tracked(vertex) {
+            None => {
+                self.insert(dist, vertex);
             }
-            prev = Some(curr);
-            current_ptr = unsafe {
-                (*curr)
-                    .sibling
-                    .as_mut()
-                    .map(|n| n.as_mut() as *mut FibNode<T>)
-            };
-        }
-
-        let min_node = if let Some(prev_ptr) = min_prev {
-            unsafe {
-                let prev_node = &mut *prev_ptr;
-                let mut min = prev_node.sibling.take().unwrap();
-                prev_node.sibling = min.sibling.take();
-                min
+            Some(x) => {
+                if self.lowers(x, dist) {
+                    self.decrease_node(x, dist);
+                }
             }
-        } else {
-            let mut min = self.roots.take().unwrap();
-            self.roots = min.sibling.take();
-            min
-        };
-
-        let result = min_node.entry;
-
-        let mut child = min_node.child;
-        while let Some(mut c) = child {
-            child = c.sibling.take();
-            self.add_to_roots(c);
         }
-
-        self.len -= 1;
-
-        if self.len > 0 {
-            self.consolidate();
-        } else {
-            self.min_node = None;
-        }
-
-        Some(result)
     }
 
     #[inline]
@@ -199,9 +444,26 @@ impl<T: FloatNumber> PriorityQueue<T> for FibonacciHeap<T> {
         self.len
     }
 
+    /// O(arena), invalidates every handle.
     fn clear(&mut self) {
-        self.roots = None;
-        self.min_node = None;
+        for x in 0..self.nodes.len() {
+            if self.nodes[x].live {
+                self.release(x);
+            }
+        }
+        self.min = NIL;
         self.len = 0;
     }
 }
+
+impl<T: FloatNumber> AddressablePriorityQueue<T> for FibonacciHeap<T> {
+    #[inline]
+    fn contains(&self, vertex: usize) -> bool {
+        self.tracked(vertex).is_some()
+    }
+
+    #[inline]
+    fn priority(&self, vertex: usize) -> Option<T> {
+        self.tracked(vertex).map(|x| self.nodes[x].entry.dist)
+    }
+}
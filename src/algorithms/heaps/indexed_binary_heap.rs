@@ -0,0 +1,178 @@
+use crate::utils::FloatNumber;
+
+use super::traits::{AddressablePriorityQueue, HeapEntry, PriorityQueue};
+
+const NOT_IN_HEAP: usize = usize::MAX;
+
+/// Binary heap keyed by vertex id: at most one entry per vertex, in-place decrease-key.
+#[derive(Clone, Debug)]
+pub struct IndexedBinaryHeap<T: FloatNumber> {
+    heap: Vec<HeapEntry<T>>,
+    pos: Vec<usize>, // pos[v] = index of v in heap, grown on demand
+}
+
+impl<T: FloatNumber> IndexedBinaryHeap<T> {
+    pub fn new() -> Self {
+        Self {
+            heap: Vec::new(),
+            pos: Vec::new(),
+        }
+    }
+
+    /// `capacity` = number of vertex ids expected.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            heap: Vec::with_capacity(capacity),
+            pos: vec![NOT_IN_HEAP; capacity],
+        }
+    }
+
+    /// Smallest entry without removing it.
+    pub fn peek(&self) -> Option<HeapEntry<T>> {
+        self.heap.first().copied()
+    }
+
+    #[inline]
+    fn index_of(&self, vertex: usize) -> Option<usize> {
+        self.pos.get(vertex).copied().filter(|&i| i != NOT_IN_HEAP)
+    }
+
+    fn insert(&mut self, dist: T, vertex: usize) {
+        if vertex >= self.pos.len() {
+            self.pos.resize(vertex + 1, NOT_IN_HEAP);
+        }
+        let i = self.heap.len();
+        self.heap.push(HeapEntry::new(dist, vertex));
+        self.pos[vertex] = i;
+        self.sift_up(i);
+    }
+
+    #[inline]
+    fn swap(&mut self, i: usize, j: usize) {
+        self.heap.swap(i, j);
+        self.pos[self.heap[i].vertex] = i;
+        self.pos[self.heap[j].vertex] = j;
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if self.heap[i] >= self.heap[parent] {
+                break;
+            }
+            self.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        let len = self.heap.len();
+        loop {
+            let left = 2 * i + 1;
+            if left >= len {
+                break;
+            }
+            let right = left + 1;
+            let child = if right < len && self.heap[right] < self.heap[left] {
+                right
+            } else {
+                left
+            };
+            if self.heap[child] >= self.heap[i] {
+                break;
+            }
+            self.swap(i, child);
+            i = child;
+        }
+    }
+}
+
+impl<T: FloatNumber> Default for IndexedBinaryHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FloatNumber> PriorityQueue<T> for IndexedBinaryHeap<T> {
+    const DECREASE_KEY: bool = true;
+
+    fn new() -> Self {
+        IndexedBinaryHeap::new()
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        IndexedBinaryHeap::with_capacity(capacity)
+    }
+
+    /// Insert, or move an existing vertex to the new key (either direction).
+    fn push(&mut self, dist: T, vertex: usize) {
+        match self.index_of(vertex) {
+            None => self.insert(dist, vertex),
+            Some(i) => {
+                let old = self.heap[i].dist;
+                self.heap[i].dist = dist;
+                if HeapEntry::cmp_dist(dist, old).is_lt() {
+                    self.sift_up(i);
+                } else {
+                    self.sift_down(i);
+                }
+            }
+        }
+    }
+
+    fn pop(&mut self) -> Option<HeapEntry<T>> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+        let top = self.heap.pop().unwrap();
+        self.pos[top.vertex] = NOT_IN_HEAP;
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+        Some(top)
+    }
+
+    /// Insert, or lower the key of a queued vertex; larger keys are ignored.
+    fn decrease_key(&mut self, dist: T, vertex: usize) {
+        match self.index_of(vertex) {
+            None => self.insert(dist, vertex),
+            Some(i) if HeapEntry::cmp_dist(dist, self.heap[i].dist).is_lt() => {
+                self.heap[i].dist = dist;
+                self.sift_up(i);
+            }
+            Some(_) => {}
+        }
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// O(len), keeps the position table allocated.
+    fn clear(&mut self) {
+        for e in &self.heap {
+            self.pos[e.vertex] = NOT_IN_HEAP;
+        }
+        self.heap.clear();
+    }
+}
+
+impl<T: FloatNumber> AddressablePriorityQueue<T> for IndexedBinaryHeap<T> {
+    #[inline]
+    fn contains(&self, vertex: usize) -> bool {
+        self.index_of(vertex).is_some()
+    }
+
+    #[inline]
+    fn priority(&self, vertex: usize) -> Option<T> {
+        self.index_of(vertex).map(|i| self.heap[i].dist)
+    }
+}
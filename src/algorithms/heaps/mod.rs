@@ -1,11 +1,19 @@
 //! Priority queue implementations. See `docs/algorithms/heaps.md`.
 
 mod binary_heap;
+mod bucket_queue;
+mod dary_heap;
 mod fibonacci_heap;
+mod indexed_binary_heap;
 mod pairing_heap;
+mod radix_heap;
 mod traits;
 
 pub use binary_heap::BinaryHeap;
-pub use fibonacci_heap::FibonacciHeap;
+pub use bucket_queue::BucketQueue;
+pub use dary_heap::DaryHeap;
+pub use fibonacci_heap::{FibHandle, FibonacciHeap};
+pub use indexed_binary_heap::IndexedBinaryHeap;
 pub use pairing_heap::PairingHeap;
-pub use traits::{HeapEntry, PriorityQueue};
+pub use radix_heap::RadixHeap;
+pub use traits::{AddressablePriorityQueue, HeapEntry, PriorityQueue};
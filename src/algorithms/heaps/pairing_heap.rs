@@ -28,7 +28,7 @@ fn meld<T: FloatNumber>(
         (None, b) => b,
         (a, None) => a,
         (Some(mut a), Some(mut b)) => {
-            if a.entry.dist <= b.entry.dist {
+            if a.entry <= b.entry {
                 b.sibling = a.child.take();
                 a.child = Some(b);
                 Some(a)
@@ -0,0 +1,112 @@
+use crate::utils::FloatNumber;
+
+use super::traits::{HeapEntry, PriorityQueue};
+
+const BUCKETS: usize = 65; // Key equal to last, or highest differing bit 0..63
+
+/// Monotone radix heap. Keys must be non-negative and never below the last
+/// popped key (true for Dijkstra). Exact for floats: non-negative `f64` bit
+/// patterns sort like the values.
+#[derive(Clone, Debug)]
+pub struct RadixHeap<T: FloatNumber> {
+    buckets: Vec<Vec<(u64, HeapEntry<T>)>>,
+    last: u64, // Bits of the last popped key
+    len: usize,
+}
+
+impl<T: FloatNumber> RadixHeap<T> {
+    pub fn new() -> Self {
+        Self {
+            buckets: vec![Vec::new(); BUCKETS],
+            last: 0,
+            len: 0,
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut heap = Self::new();
+        heap.buckets[0].reserve(capacity);
+        heap
+    }
+
+    #[inline]
+    fn bits(dist: T) -> u64 {
+        let x = dist.to_f64().unwrap_or(f64::NAN);
+        debug_assert!(x >= 0.0, "RadixHeap keys must be non-negative");
+        if x == 0.0 {
+            0 // Fold -0.0 into +0.0
+        } else {
+            x.to_bits()
+        }
+    }
+
+    #[inline]
+    fn bucket(&self, key: u64) -> usize {
+        (u64::BITS - (key ^ self.last).leading_zeros()) as usize
+    }
+}
+
+impl<T: FloatNumber> Default for RadixHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FloatNumber> PriorityQueue<T> for RadixHeap<T> {
+    fn new() -> Self {
+        RadixHeap::new()
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        RadixHeap::with_capacity(capacity)
+    }
+
+    #[inline]
+    fn push(&mut self, dist: T, vertex: usize) {
+        let key = Self::bits(dist);
+        debug_assert!(key >= self.last, "RadixHeap key below last popped key");
+        let b = self.bucket(key);
+        self.buckets[b].push((key, HeapEntry::new(dist, vertex)));
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<HeapEntry<T>> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // Refill bucket 0 from the first non-empty bucket, around its min key
+        if self.buckets[0].is_empty() {
+            let i = (1..BUCKETS).find(|&i| !self.buckets[i].is_empty()).unwrap();
+            let items = std::mem::take(&mut self.buckets[i]);
+            self.last = items.iter().map(|&(k, _)| k).min().unwrap();
+            for &(key, entry) in &items {
+                let b = self.bucket(key);
+                self.buckets[b].push((key, entry));
+            }
+            self.buckets[i] = items;
+            self.buckets[i].clear(); // Keep the allocation
+        }
+
+        self.len -= 1;
+        self.buckets[0].pop().map(|(_, entry)| entry)
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn clear(&mut self) {
+        for bucket in &mut self.buckets {
+            bucket.clear();
+        }
+        self.last = 0;
+        self.len = 0;
+    }
+}
@@ -1,6 +1,9 @@
 use crate::utils::FloatNumber;
+use std::cmp::Ordering;
 use std::fmt::Debug;
 
+/// Queue entry. Ordered by `dist`, then `vertex`, so equal distances pop
+/// lowest vertex first in every comparison heap. NaN sorts after +inf.
 #[derive(Clone, Copy, Debug)]
 pub struct HeapEntry<T: FloatNumber> {
     pub dist: T,
@@ -12,10 +15,43 @@ impl<T: FloatNumber> HeapEntry<T> {
     pub fn new(dist: T, vertex: usize) -> Self {
         Self { dist, vertex }
     }
+
+    /// Total order on distances: numeric, `-0.0 == 0.0`, NaN last (all NaNs equal).
+    #[inline]
+    pub fn cmp_dist(a: T, b: T) -> Ordering {
+        match a.partial_cmp(&b) {
+            Some(ord) => ord,
+            None => a.is_nan().cmp(&b.is_nan()),
+        }
+    }
+}
+
+impl<T: FloatNumber> PartialEq for HeapEntry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: FloatNumber> Eq for HeapEntry<T> {}
+
+impl<T: FloatNumber> PartialOrd for HeapEntry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: FloatNumber> Ord for HeapEntry<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        Self::cmp_dist(self.dist, other.dist).then(self.vertex.cmp(&other.vertex))
+    }
 }
 
 /// Min-heap interface. `pop()` returns smallest distance.
 pub trait PriorityQueue<T: FloatNumber>: Default + Debug {
+    /// `decrease_key` updates in place, so popped entries are never stale.
+    const DECREASE_KEY: bool = false;
+
     fn new() -> Self;
     fn with_capacity(capacity: usize) -> Self;
     fn push(&mut self, dist: T, vertex: usize);
@@ -23,4 +59,18 @@ pub trait PriorityQueue<T: FloatNumber>: Default + Debug {
     fn is_empty(&self) -> bool;
     fn len(&self) -> usize;
     fn clear(&mut self);
+
+    /// Lower the key of `vertex`, inserting it if absent.
+    /// Default pushes a duplicate; callers must then skip stale pops.
+    #[inline]
+    fn decrease_key(&mut self, dist: T, vertex: usize) {
+        self.push(dist, vertex);
+    }
+}
+
+/// Heap that tracks each vertex's entry, at most one per vertex.
+pub trait AddressablePriorityQueue<T: FloatNumber>: PriorityQueue<T> {
+    fn contains(&self, vertex: usize) -> bool;
+    /// Current key of a queued vertex.
+    fn priority(&self, vertex: usize) -> Option<T>;
 }
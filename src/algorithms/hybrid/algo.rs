@@ -0,0 +1,389 @@
+use crate::algorithms::heaps::{BinaryHeap, PriorityQueue};
+use crate::algorithms::HasSsspConfig;
+use crate::algorithms::{finalize_sssp, init_sssp, SsspAlgorithm, SsspAlgorithmInfo, SsspResult};
+use crate::utils::{FloatNumber, Graph, SsspBuffers};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim};
+use std::marker::PhantomData;
+
+use super::block::BlockList;
+use super::config::HybridConfig;
+
+#[derive(Debug)]
+pub struct Hybrid<T: FloatNumber, H: PriorityQueue<T> = BinaryHeap<T>> {
+    config: HybridConfig,
+    heap: H,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: FloatNumber, H: PriorityQueue<T>> Hybrid<T, H> {
+    pub fn new() -> Self {
+        Self {
+            config: HybridConfig::default(),
+            heap: H::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn with_config(config: HybridConfig) -> Self {
+        Self {
+            config,
+            heap: H::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            config: HybridConfig::default(),
+            heap: H::with_capacity(capacity),
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn config(&self) -> &HybridConfig {
+        &self.config
+    }
+
+    pub fn config_mut(&mut self) -> &mut HybridConfig {
+        &mut self.config
+    }
+}
+
+impl<T: FloatNumber> Default for Hybrid<T, BinaryHeap<T>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FloatNumber, H: PriorityQueue<T>> SsspAlgorithmInfo for Hybrid<T, H> {
+    fn name(&self) -> &'static str {
+        "Hybrid"
+    }
+
+    fn supports_negative_weights(&self) -> bool {
+        false
+    }
+
+    fn target(&self) -> Option<usize> {
+        self.config.sssp_config().early_stop
+    }
+}
+
+impl<T, N, G, H> SsspAlgorithm<T, N, G> for Hybrid<T, H>
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T>,
+    H: PriorityQueue<T>,
+    DefaultAllocator: Allocator<N>,
+{
+    fn run(&mut self, graph: &G, source: usize, buffers: &mut SsspBuffers<T, N>) -> SsspResult<T> {
+        debug_assert!(source < graph.n(), "Source vertex out of bounds");
+
+        init_sssp(buffers, source);
+
+        let n = graph.n();
+        let (k, t) = self.config.params(n);
+        let levels = self.config.levels(n);
+
+        let mut state = Bmssp {
+            graph,
+            dist: buffers.dist.as_mut_slice(),
+            parent: buffers.parent.as_mut_slice(),
+            heap: &mut self.heap,
+            config: &self.config,
+            k,
+            t,
+            in_w: Marks::new(n),
+            visited: Marks::new(n),
+            settled: 0,
+            stopped: false,
+        };
+        state.bmssp(levels, T::infinity(), &[source]);
+        let iterations = state.settled;
+
+        finalize_sssp(buffers, iterations, false)
+    }
+}
+
+/// Epoch-stamped vertex set, cleared in O(1).
+struct Marks {
+    stamp: Vec<usize>,
+    epoch: usize,
+}
+
+impl Marks {
+    fn new(n: usize) -> Self {
+        Self {
+            stamp: vec![0; n],
+            epoch: 0,
+        }
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        self.epoch += 1;
+    }
+
+    /// Returns true if v was not yet marked.
+    #[inline]
+    fn mark(&mut self, v: usize) -> bool {
+        let fresh = self.stamp[v] != self.epoch;
+        self.stamp[v] = self.epoch;
+        fresh
+    }
+
+    #[inline]
+    fn contains(&self, v: usize) -> bool {
+        self.stamp[v] == self.epoch
+    }
+}
+
+/// Per-run state of the bounded multi-source shortest path recursion.
+struct Bmssp<'a, T: FloatNumber, G, H> {
+    graph: &'a G,
+    dist: &'a mut [T],
+    parent: &'a mut [usize],
+    heap: &'a mut H,
+    config: &'a HybridConfig,
+    k: usize,
+    t: usize,
+    in_w: Marks,
+    visited: Marks,
+    settled: usize,
+    stopped: bool,
+}
+
+impl<T, G, H> Bmssp<'_, T, G, H>
+where
+    T: FloatNumber,
+    G: Graph<T>,
+    H: PriorityQueue<T>,
+{
+    /// Relax (u -> v). Ties count as relaxed so upper levels re-see edges
+    /// already settled below; parents only move on strict improvement.
+    #[inline]
+    fn relax(dist: &mut [T], parent: &mut [usize], u: usize, v: usize, w: T) -> Option<T> {
+        let new_dist = dist[u] + w;
+        if new_dist > dist[v] {
+            return None;
+        }
+        if new_dist < dist[v] {
+            dist[v] = new_dist;
+            parent[v] = u;
+        }
+        Some(new_dist)
+    }
+
+    /// Mark a vertex complete, flagging early stop on the target.
+    #[inline]
+    fn settle(&mut self, v: usize) {
+        if self.config.should_stop(v) {
+            self.stopped = true;
+        }
+    }
+
+    /// BMSSP(l, B, S): complete every vertex whose shortest path visits S and
+    /// has length < B', returning (B', U). B' == B unless U hit its size cap.
+    fn bmssp(&mut self, level: usize, bound: T, sources: &[usize]) -> (T, Vec<usize>) {
+        if level == 0 {
+            return self.base_case(bound, sources);
+        }
+
+        let (pivots, w) = self.find_pivots(bound, sources);
+
+        let pull_size = pow2((level - 1) * self.t);
+        let cap = self.k.saturating_mul(pow2(level * self.t));
+
+        let mut blocks = BlockList::new(pull_size, bound);
+        let mut last_bound = bound;
+        for &x in &pivots {
+            blocks.insert(x, self.dist[x]);
+            if self.dist[x] < last_bound {
+                last_bound = self.dist[x];
+            }
+        }
+
+        let mut complete = Vec::new();
+        while complete.len() < cap && !blocks.is_empty() && !self.stopped {
+            let (sub_bound, sub_sources) = blocks.pull();
+            let (sub_done, sub_complete) = self.bmssp(level - 1, sub_bound, &sub_sources);
+            last_bound = sub_done;
+
+            let mut prepend = Vec::new();
+            for &u in &sub_complete {
+                let (dist, parent) = (&mut *self.dist, &mut *self.parent);
+                self.graph.for_each_out_edge(u, |v, w| {
+                    if let Some(d) = Self::relax(dist, parent, u, v, w) {
+                        if d >= sub_bound && d < bound {
+                            blocks.insert(v, d);
+                        } else if d >= sub_done && d < sub_bound {
+                            prepend.push((v, d));
+                        }
+                    }
+                });
+            }
+            for &x in &sub_sources {
+                let d = self.dist[x];
+                if d >= sub_done && d < sub_bound {
+                    prepend.push((x, d));
+                }
+            }
+            blocks.batch_prepend(prepend);
+
+            complete.extend(sub_complete);
+        }
+
+        let done = if last_bound < bound {
+            last_bound
+        } else {
+            bound
+        };
+
+        // Vertices near S finished during FindPivots
+        self.visited.clear();
+        for &v in &complete {
+            self.visited.mark(v);
+        }
+        for v in w {
+            if self.dist[v] < done && self.visited.mark(v) {
+                complete.push(v);
+                self.settle(v);
+            }
+        }
+
+        (done, complete)
+    }
+
+    /// Bellman-Ford style k-step expansion from S. Returns (pivots P, visited W).
+    /// Only roots of shortest-path trees with >= k vertices become pivots.
+    fn find_pivots(&mut self, bound: T, sources: &[usize]) -> (Vec<usize>, Vec<usize>) {
+        self.in_w.clear();
+        let mut w: Vec<usize> = Vec::with_capacity(sources.len());
+        for &s in sources {
+            if self.in_w.mark(s) {
+                w.push(s);
+            }
+        }
+
+        let mut frontier = w.clone();
+        for _ in 0..self.k {
+            let mut next = Vec::new();
+            self.visited.clear();
+            for &u in &frontier {
+                let (dist, parent, visited) =
+                    (&mut *self.dist, &mut *self.parent, &mut self.visited);
+                self.graph.for_each_out_edge(u, |v, wt| {
+                    if let Some(d) = Self::relax(dist, parent, u, v, wt) {
+                        if d < bound && visited.mark(v) {
+                            next.push(v);
+                        }
+                    }
+                });
+            }
+
+            for &v in &next {
+                if self.in_w.mark(v) {
+                    w.push(v);
+                }
+            }
+            if w.len() > self.k * sources.len() {
+                return (sources.to_vec(), w);
+            }
+            frontier = next;
+        }
+
+        // Tight-edge forest over W; DFS capped at k vertices per root
+        let mut pivots = Vec::new();
+        for &root in sources {
+            self.visited.clear();
+            self.visited.mark(root);
+            let mut count = 1usize;
+            let mut stack = vec![root];
+
+            while let Some(u) = stack.pop() {
+                if count >= self.k {
+                    break;
+                }
+                let d_u = self.dist[u];
+                let (dist, in_w, visited) = (&*self.dist, &self.in_w, &mut self.visited);
+                self.graph.for_each_out_edge(u, |v, wt| {
+                    if in_w.contains(v) && dist[v] == d_u + wt && visited.mark(v) {
+                        count += 1;
+                        stack.push(v);
+                    }
+                });
+            }
+
+            if count >= self.k {
+                pivots.push(root);
+            }
+        }
+
+        (pivots, w)
+    }
+
+    /// Level 0: bounded multi-source Dijkstra until just over k vertices settle.
+    fn base_case(&mut self, bound: T, sources: &[usize]) -> (T, Vec<usize>) {
+        self.heap.clear();
+        self.visited.clear();
+        for &x in sources {
+            self.heap.push(self.dist[x], x);
+        }
+
+        let mut complete = Vec::new();
+        let mut last = T::neg_infinity();
+
+        while let Some(entry) = self.heap.pop() {
+            let u = entry.vertex;
+            let d_u = entry.dist;
+
+            if d_u > self.dist[u] || self.visited.contains(u) {
+                continue;
+            }
+
+            // Stop between tie classes once over k settled
+            if complete.len() > self.k && d_u > last {
+                return (d_u, complete);
+            }
+
+            self.visited.mark(u);
+            complete.push(u);
+            last = d_u;
+            self.settled += 1;
+            self.settle(u);
+            if self.stopped {
+                break;
+            }
+
+            let (dist, parent, visited, heap) = (
+                &mut *self.dist,
+                &mut *self.parent,
+                &self.visited,
+                &mut *self.heap,
+            );
+            self.graph.for_each_out_edge(u, |v, w| {
+                debug_assert!(w >= T::zero(), "Hybrid requires non-negative weights");
+
+                if visited.contains(v) {
+                    return;
+                }
+                if let Some(d) = Self::relax(dist, parent, u, v, w) {
+                    if d < bound {
+                        heap.decrease_key(d, v);
+                    }
+                }
+            });
+        }
+
+        (bound, complete)
+    }
+}
+
+#[inline]
+fn pow2(exp: usize) -> usize {
+    u32::try_from(exp)
+        .ok()
+        .and_then(|e| 1usize.checked_shl(e))
+        .unwrap_or(usize::MAX)
+}
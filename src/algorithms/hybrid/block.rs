@@ -0,0 +1,317 @@
+use crate::utils::FloatNumber;
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+
+/// Block entry. Only live while `stamp` matches the vertex's current key.
+#[derive(Clone, Copy, Debug)]
+struct Item<T: FloatNumber> {
+    vertex: usize,
+    key: T,
+    stamp: usize,
+}
+
+#[derive(Debug)]
+struct Block<T: FloatNumber> {
+    upper: T,
+    items: Vec<Item<T>>,
+}
+
+#[inline]
+fn cmp_key<T: FloatNumber>(a: &Item<T>, b: &Item<T>) -> Ordering {
+    a.key.partial_cmp(&b.key).unwrap_or(Ordering::Equal)
+}
+
+/// Partial-sorting block list (Lemma 3.3 of the paper).
+///
+/// `d0` holds batch-prepended blocks in key order, `d1` holds inserted
+/// blocks sorted by upper bound. Blocks hold at most `m` live items and are
+/// split around their median, so nothing is ever fully sorted.
+#[derive(Debug)]
+pub(super) struct BlockList<T: FloatNumber> {
+    m: usize,
+    bound: T,
+    d0: VecDeque<Vec<Item<T>>>,
+    d1: Vec<Block<T>>,
+    keys: HashMap<usize, (T, usize)>,
+    stamp: usize,
+}
+
+impl<T: FloatNumber> BlockList<T> {
+    pub fn new(m: usize, bound: T) -> Self {
+        Self {
+            m: m.max(1),
+            bound,
+            d0: VecDeque::new(),
+            d1: vec![Block {
+                upper: bound,
+                items: Vec::new(),
+            }],
+            keys: HashMap::new(),
+            stamp: 0,
+        }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Record `key` for `v` if it beats the current one.
+    fn claim(&mut self, vertex: usize, key: T) -> Option<Item<T>> {
+        if let Some(&(curr, _)) = self.keys.get(&vertex) {
+            if curr <= key {
+                return None;
+            }
+        }
+        self.stamp += 1;
+        self.keys.insert(vertex, (key, self.stamp));
+        Some(Item {
+            vertex,
+            key,
+            stamp: self.stamp,
+        })
+    }
+
+    /// Insert (or decrease) a single key. Requires `key < bound`.
+    pub fn insert(&mut self, vertex: usize, key: T) {
+        debug_assert!(key < self.bound, "Key outside block list bound");
+
+        let Some(item) = self.claim(vertex, key) else {
+            return;
+        };
+
+        let idx = self.d1.partition_point(|b| b.upper < key);
+        self.d1[idx].items.push(item);
+        if self.d1[idx].items.len() > self.m {
+            self.split(idx);
+        }
+    }
+
+    /// Prepend keys no larger than any key currently held.
+    pub fn batch_prepend(&mut self, items: Vec<(usize, T)>) {
+        let fresh: Vec<Item<T>> = items
+            .into_iter()
+            .filter_map(|(v, key)| self.claim(v, key))
+            .collect();
+
+        if fresh.is_empty() {
+            return;
+        }
+
+        if fresh.len() <= self.m {
+            self.d0.push_front(fresh);
+            return;
+        }
+
+        let mut blocks = Vec::new();
+        split_by_median(fresh, self.m.div_ceil(2), &mut blocks);
+        for block in blocks.into_iter().rev() {
+            self.d0.push_front(block);
+        }
+    }
+
+    /// Remove the smallest keys (about `m` of them).
+    /// Returns (bound, vertices) with every returned key < bound <= every remaining key.
+    pub fn pull(&mut self) -> (T, Vec<usize>) {
+        let mut cand = Vec::new();
+        let d0_scanned = self.collect_d0(&mut cand);
+        let d1_scanned = self.collect_d1(&mut cand);
+
+        if cand.len() <= self.m {
+            // Both sides exhausted
+            self.take(&cand);
+            self.prune(d0_scanned, d1_scanned);
+            return (self.bound, cand.iter().map(|it| it.vertex).collect());
+        }
+
+        // (m+1)-th smallest splits pulled from kept; everything unscanned is >= it
+        let m = self.m;
+        cand.select_nth_unstable_by(m, cmp_key);
+        let split = cand[m].key;
+        let pulled: Vec<Item<T>> = cand.iter().copied().filter(|it| it.key < split).collect();
+
+        if !pulled.is_empty() {
+            self.take(&pulled);
+            self.prune(d0_scanned, d1_scanned);
+            return (split, pulled.iter().map(|it| it.vertex).collect());
+        }
+
+        // Ties at the minimum: pull the whole tie class
+        let (ties, d0_scanned, d1_scanned) = self.collect_equal(split);
+        self.take(&ties);
+        self.prune(d0_scanned, d1_scanned);
+        let bound = self.min_key().unwrap_or(self.bound);
+        (bound, ties.iter().map(|it| it.vertex).collect())
+    }
+
+    #[inline]
+    fn is_live(&self, item: &Item<T>) -> bool {
+        self.keys.get(&item.vertex).map(|&(_, s)| s) == Some(item.stamp)
+    }
+
+    /// Scan d0 blocks from the front until more than m live items are seen.
+    fn collect_d0(&self, cand: &mut Vec<Item<T>>) -> usize {
+        let mut seen = 0usize;
+        let mut scanned = 0usize;
+        for block in &self.d0 {
+            scanned += 1;
+            for it in block.iter().filter(|it| self.is_live(it)) {
+                cand.push(*it);
+                seen += 1;
+            }
+            if seen > self.m {
+                break;
+            }
+        }
+        scanned
+    }
+
+    /// Scan d1 blocks from the front until more than m live items are seen.
+    fn collect_d1(&self, cand: &mut Vec<Item<T>>) -> usize {
+        let mut seen = 0usize;
+        let mut scanned = 0usize;
+        for block in &self.d1 {
+            scanned += 1;
+            for it in block.items.iter().filter(|it| self.is_live(it)) {
+                cand.push(*it);
+                seen += 1;
+            }
+            if seen > self.m {
+                break;
+            }
+        }
+        scanned
+    }
+
+    /// Live items with key == `key`, plus blocks scanned per list.
+    /// Both lists are key-ordered, so stop at the first block past `key`.
+    fn collect_equal(&self, key: T) -> (Vec<Item<T>>, usize, usize) {
+        let mut out = Vec::new();
+        let mut d0_scanned = 0usize;
+        for block in &self.d0 {
+            d0_scanned += 1;
+            let mut past = true;
+            for it in block.iter().filter(|it| self.is_live(it)) {
+                if it.key <= key {
+                    past = false;
+                }
+                if it.key == key {
+                    out.push(*it);
+                }
+            }
+            if past && block.iter().any(|it| self.is_live(it)) {
+                break;
+            }
+        }
+
+        let mut d1_scanned = 0usize;
+        for block in &self.d1 {
+            d1_scanned += 1;
+            out.extend(
+                block
+                    .items
+                    .iter()
+                    .filter(|it| it.key == key && self.is_live(it)),
+            );
+            if block.upper > key {
+                break;
+            }
+        }
+        (out, d0_scanned, d1_scanned)
+    }
+
+    fn take(&mut self, items: &[Item<T>]) {
+        for it in items {
+            self.keys.remove(&it.vertex);
+        }
+    }
+
+    /// Drop dead items from the first scanned blocks, then drop empty blocks.
+    fn prune(&mut self, d0_scanned: usize, d1_scanned: usize) {
+        let keys = &self.keys;
+        let live = |it: &Item<T>| keys.get(&it.vertex).map(|&(_, s)| s) == Some(it.stamp);
+
+        for block in self.d0.iter_mut().take(d0_scanned) {
+            block.retain(live);
+        }
+        for block in self.d1.iter_mut().take(d1_scanned) {
+            block.items.retain(live);
+        }
+
+        self.d0.retain(|b| !b.is_empty());
+        let last = self.d1.len() - 1;
+        let mut idx = 0usize;
+        self.d1.retain(|b| {
+            let keep = idx == last || !b.items.is_empty();
+            idx += 1;
+            keep
+        });
+    }
+
+    /// Smallest live key, from the first live block of each list.
+    fn min_key(&self) -> Option<T> {
+        let first_d0 = self.d0.iter().find(|b| b.iter().any(|it| self.is_live(it)));
+        let first_d1 = self
+            .d1
+            .iter()
+            .find(|b| b.items.iter().any(|it| self.is_live(it)));
+
+        first_d0
+            .into_iter()
+            .flat_map(|b| b.iter())
+            .chain(first_d1.into_iter().flat_map(|b| b.items.iter()))
+            .filter(|it| self.is_live(it))
+            .map(|it| it.key)
+            .fold(None, |acc: Option<T>, k| match acc {
+                Some(a) if a <= k => Some(a),
+                _ => Some(k),
+            })
+    }
+
+    /// Split an oversized d1 block around its median.
+    fn split(&mut self, idx: usize) {
+        let keys = &self.keys;
+        let block = &mut self.d1[idx];
+        block
+            .items
+            .retain(|it| keys.get(&it.vertex).map(|&(_, s)| s) == Some(it.stamp));
+        if block.items.len() <= self.m {
+            return;
+        }
+
+        let mid = block.items.len() / 2;
+        block.items.select_nth_unstable_by(mid, cmp_key);
+        let upper_items = block.items.split_off(mid);
+        let upper = block.upper;
+        block.upper = block
+            .items
+            .iter()
+            .map(|it| it.key)
+            .fold(T::neg_infinity(), |a, k| if k > a { k } else { a });
+
+        self.d1.insert(
+            idx + 1,
+            Block {
+                upper,
+                items: upper_items,
+            },
+        );
+    }
+}
+
+/// Recursively split around medians into key-ordered blocks of at most `size`.
+fn split_by_median<T: FloatNumber>(
+    mut items: Vec<Item<T>>,
+    size: usize,
+    out: &mut Vec<Vec<Item<T>>>,
+) {
+    if items.len() <= size {
+        out.push(items);
+        return;
+    }
+    let mid = items.len() / 2;
+    items.select_nth_unstable_by(mid, cmp_key);
+    let upper = items.split_off(mid);
+    split_by_median(items, size, out);
+    split_by_median(upper, size, out);
+}
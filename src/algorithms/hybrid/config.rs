@@ -0,0 +1,50 @@
+use crate::algorithms::{HasSsspConfig, SsspConfig};
+
+#[derive(Clone, Debug, Default)]
+pub struct HybridConfig {
+    base: SsspConfig,
+    pub k: Option<usize>, // Pivot/base-case size, default floor(log^(1/3) n)
+    pub t: Option<usize>, // Level step, default floor(log^(2/3) n)
+}
+
+impl HybridConfig {
+    pub fn with_target(target: usize) -> Self {
+        Self {
+            base: SsspConfig::with_target(target),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_params(mut self, k: usize, t: usize) -> Self {
+        self.k = Some(k);
+        self.t = Some(t);
+        self
+    }
+
+    /// Resolve (k, t) for a graph with n vertices.
+    pub fn params(&self, n: usize) -> (usize, usize) {
+        let log_n = (n.max(2) as f64).log2();
+        let k = self
+            .k
+            .unwrap_or_else(|| log_n.powf(1.0 / 3.0).floor() as usize)
+            .max(1);
+        let t = self
+            .t
+            .unwrap_or_else(|| log_n.powf(2.0 / 3.0).floor() as usize)
+            .max(1);
+        (k, t)
+    }
+
+    /// Recursion depth of the top-level call, ceil(log n / t).
+    pub fn levels(&self, n: usize) -> usize {
+        let (_, t) = self.params(n);
+        let log_n = (n.max(2) as f64).log2();
+        ((log_n / t as f64).ceil() as usize).max(1)
+    }
+}
+
+impl HasSsspConfig for HybridConfig {
+    fn sssp_config(&self) -> &SsspConfig {
+        &self.base
+    }
+}
@@ -0,0 +1,45 @@
+//! Deterministic O(m log^(2/3) n) SSSP via bounded multi-source recursion.
+//! See `docs/algorithms/hybrid.md`.
+
+mod algo;
+mod block;
+mod config;
+
+pub use algo::Hybrid;
+pub use config::HybridConfig;
+
+use crate::algorithms::heaps::BinaryHeap;
+use crate::algorithms::{SsspAlgorithm, SsspResult};
+use crate::utils::{FloatNumber, Graph, SsspBuffers};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim};
+
+/// One-shot Hybrid execute.
+pub fn cheeky_hybrid<T, N, G>(
+    graph: &G,
+    source: usize,
+    buffers: &mut SsspBuffers<T, N>,
+) -> SsspResult<T>
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T>,
+    DefaultAllocator: Allocator<N>,
+{
+    Hybrid::<T, BinaryHeap<T>>::new().run(graph, source, buffers)
+}
+
+pub fn hybrid_to<T, N, G>(
+    graph: &G,
+    source: usize,
+    target: usize,
+    buffers: &mut SsspBuffers<T, N>,
+) -> SsspResult<T>
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T>,
+    DefaultAllocator: Allocator<N>,
+{
+    Hybrid::<T, BinaryHeap<T>>::with_config(HybridConfig::with_target(target))
+        .run(graph, source, buffers)
+}
@@ -0,0 +1,204 @@
+use crate::algorithms::heaps::BinaryHeap;
+use crate::algorithms::{finalize_apsp, ApspAlgorithm, ApspAlgorithmInfo, ApspResult};
+use crate::algorithms::{BellmanFord, Dijkstra, SsspAlgorithm};
+use crate::utils::{ApspBuffers, FloatNumber, Graph, SsspBuffers, APSP_NO_PATH};
+use nalgebra::Dyn;
+use rayon::prelude::*;
+
+use super::config::JohnsonConfig;
+
+#[derive(Debug)]
+pub struct Johnson<T: FloatNumber> {
+    config: JohnsonConfig,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: FloatNumber> Johnson<T> {
+    pub fn new() -> Self {
+        Self {
+            config: JohnsonConfig::default(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    pub fn with_config(config: JohnsonConfig) -> Self {
+        Self {
+            config,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    pub fn config(&self) -> &JohnsonConfig {
+        &self.config
+    }
+
+    pub fn config_mut(&mut self) -> &mut JohnsonConfig {
+        &mut self.config
+    }
+}
+
+impl<T: FloatNumber> Default for Johnson<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FloatNumber> ApspAlgorithmInfo for Johnson<T> {
+    fn name(&self) -> &'static str {
+        "Johnson"
+    }
+
+    fn supports_negative_weights(&self) -> bool {
+        true
+    }
+}
+
+impl<T, G> ApspAlgorithm<T, G> for Johnson<T>
+where
+    T: FloatNumber,
+    G: Graph<T> + Sync,
+{
+    fn run(&mut self, graph: &G, buffers: &mut ApspBuffers<T>) -> ApspResult<T> {
+        let n = graph.n();
+        debug_assert!(buffers.n == n, "Buffer size mismatch");
+
+        buffers.reset();
+        if n == 0 {
+            return finalize_apsp(buffers, 0, false);
+        }
+
+        // Potentials h(v) = dist from a virtual vertex n with 0-weight edges to all
+        let mut potential = SsspBuffers::<T, Dyn>::new_inf(Dyn(n + 1));
+        let bf = BellmanFord::<T>::new().run(&SuperSource { graph }, n, &mut potential);
+        if bf.negative_cycle {
+            // Virtual vertex has no in-edges, so the cycle is in the original graph
+            let mut result = finalize_apsp(buffers, bf.iterations, true);
+            result.cycle = bf.cycle;
+            return result;
+        }
+
+        let h = &potential.dist.as_slice()[..n];
+        let reweighted = Reweighted {
+            graph,
+            potential: h,
+        };
+
+        let iterations: usize = if self.config.parallel {
+            buffers
+                .dist
+                .par_chunks_mut(n)
+                .zip(buffers.next.par_chunks_mut(n))
+                .enumerate()
+                .map(|(u, (dist_row, next_row))| fill_row(&reweighted, u, h, dist_row, next_row))
+                .sum()
+        } else {
+            buffers
+                .dist
+                .chunks_mut(n)
+                .zip(buffers.next.chunks_mut(n))
+                .enumerate()
+                .map(|(u, (dist_row, next_row))| fill_row(&reweighted, u, h, dist_row, next_row))
+                .sum()
+        };
+
+        finalize_apsp(buffers, bf.iterations + iterations, false)
+    }
+}
+
+/// Graph plus a virtual vertex n with 0-weight edges to every vertex.
+struct SuperSource<'a, G> {
+    graph: &'a G,
+}
+
+impl<T: FloatNumber, G: Graph<T>> Graph<T> for SuperSource<'_, G> {
+    fn n(&self) -> usize {
+        self.graph.n() + 1
+    }
+
+    fn for_each_out_edge<F: FnMut(usize, T)>(&self, u: usize, mut f: F) {
+        if u == self.graph.n() {
+            for v in 0..u {
+                f(v, T::zero());
+            }
+        } else {
+            self.graph.for_each_out_edge(u, f);
+        }
+    }
+}
+
+/// Edge weights w'(u, v) = w(u, v) + h(u) - h(v), non-negative for valid potentials.
+struct Reweighted<'a, T: FloatNumber, G> {
+    graph: &'a G,
+    potential: &'a [T],
+}
+
+impl<T: FloatNumber, G: Graph<T>> Graph<T> for Reweighted<'_, T, G> {
+    fn n(&self) -> usize {
+        self.graph.n()
+    }
+
+    fn for_each_out_edge<F: FnMut(usize, T)>(&self, u: usize, mut f: F) {
+        let h_u = self.potential[u];
+        self.graph.for_each_out_edge(u, |v, w| {
+            // Clamp float round-off on tight edges
+            let w_prime = w + h_u - self.potential[v];
+            let w_prime = if w_prime < T::zero() {
+                T::zero()
+            } else {
+                w_prime
+            };
+            f(v, w_prime);
+        });
+    }
+}
+
+/// Dijkstra from u on the reweighted graph, writing original-weight row u.
+fn fill_row<T, G>(
+    graph: &Reweighted<'_, T, G>,
+    u: usize,
+    h: &[T],
+    dist_row: &mut [T],
+    next_row: &mut [usize],
+) -> usize
+where
+    T: FloatNumber,
+    G: Graph<T>,
+{
+    let n = dist_row.len();
+    let mut sssp = SsspBuffers::<T, Dyn>::new_inf(Dyn(n));
+    let result = Dijkstra::<T, BinaryHeap<T>>::new().run(graph, u, &mut sssp);
+
+    for v in 0..n {
+        let d = sssp.dist[v];
+        if !d.is_infinite() {
+            dist_row[v] = d - h[u] + h[v];
+        }
+    }
+
+    // Next hop = first vertex after u on the tree path, memoised up the parent chain
+    next_row[u] = u;
+    let mut stack = Vec::new();
+    for v in 0..n {
+        if sssp.dist[v].is_infinite() || next_row[v] != APSP_NO_PATH {
+            continue;
+        }
+
+        let mut x = v;
+        while next_row[x] == APSP_NO_PATH {
+            let p = sssp.parent[x];
+            if p == u {
+                next_row[x] = x;
+                break;
+            }
+            stack.push(x);
+            x = p;
+        }
+
+        let hop = next_row[x];
+        while let Some(y) = stack.pop() {
+            next_row[y] = hop;
+        }
+    }
+
+    result.iterations
+}
@@ -0,0 +1,21 @@
+#[derive(Clone, Debug)]
+pub struct JohnsonConfig {
+    pub parallel: bool, // Run per-source Dijkstra across threads
+}
+
+impl Default for JohnsonConfig {
+    fn default() -> Self {
+        Self { parallel: true }
+    }
+}
+
+impl JohnsonConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn sequential(mut self) -> Self {
+        self.parallel = false;
+        self
+    }
+}
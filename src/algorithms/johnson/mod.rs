@@ -0,0 +1,17 @@
+mod algo;
+mod config;
+
+pub use algo::Johnson;
+pub use config::JohnsonConfig;
+
+use crate::algorithms::{ApspAlgorithm, ApspResult};
+use crate::utils::{ApspBuffers, FloatNumber, Graph};
+
+/// One-shot Johnson execute.
+pub fn cheeky_johnson<T, G>(graph: &G, buffers: &mut ApspBuffers<T>) -> ApspResult<T>
+where
+    T: FloatNumber,
+    G: Graph<T> + Sync,
+{
+    Johnson::<T>::new().run(graph, buffers)
+}
@@ -0,0 +1,109 @@
+use crate::algorithms::{
+    finalize_mst, init_mst, root_forest, MstAlgorithm, MstAlgorithmInfo, MstResult,
+};
+use crate::utils::{DisjointSet, FloatNumber, Graph, MstBuffers};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim};
+
+use super::config::KruskalConfig;
+
+/// Minimum spanning forest by sorted edge scan + union-find.
+#[derive(Debug)]
+pub struct Kruskal<T: FloatNumber> {
+    config: KruskalConfig,
+    edges: Vec<(T, usize, usize)>,
+    sets: DisjointSet,
+    forest: Vec<Vec<(usize, T)>>, // Accepted edges, both directions
+}
+
+impl<T: FloatNumber> Kruskal<T> {
+    pub fn new() -> Self {
+        Self::with_config(KruskalConfig::default())
+    }
+
+    pub fn with_config(config: KruskalConfig) -> Self {
+        Self {
+            config,
+            edges: Vec::new(),
+            sets: DisjointSet::default(),
+            forest: Vec::new(),
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            edges: Vec::with_capacity(capacity),
+            ..Self::new()
+        }
+    }
+
+    pub fn config(&self) -> &KruskalConfig {
+        &self.config
+    }
+
+    pub fn config_mut(&mut self) -> &mut KruskalConfig {
+        &mut self.config
+    }
+}
+
+impl<T: FloatNumber> Default for Kruskal<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FloatNumber> MstAlgorithmInfo for Kruskal<T> {
+    fn name(&self) -> &'static str {
+        "Kruskal"
+    }
+}
+
+impl<T, N, G> MstAlgorithm<T, N, G> for Kruskal<T>
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T>,
+    DefaultAllocator: Allocator<N>,
+{
+    /// Spans every component. The tree containing `source` is rooted there,
+    /// the others at their smallest vertex; `is_connected` means one tree.
+    fn run(&mut self, graph: &G, source: usize, buffers: &mut MstBuffers<T, N>) -> MstResult<T> {
+        debug_assert!(source < graph.n(), "Source vertex out of bounds");
+
+        let n = graph.n();
+        init_mst(buffers, source);
+
+        self.edges.clear();
+        for u in 0..n {
+            graph.for_each_out_edge(u, |v, w| {
+                if u != v {
+                    self.edges.push((w, u, v));
+                }
+            });
+        }
+        // Ties by endpoints, so the forest is deterministic
+        self.edges
+            .sort_unstable_by(|a, b| a.partial_cmp(b).expect("Edge weight is NaN"));
+
+        self.sets.reset(n);
+        self.forest.iter_mut().for_each(Vec::clear);
+        self.forest.resize_with(n, Vec::new);
+
+        let mut iterations = 0usize;
+        for &(w, u, v) in &self.edges {
+            if self.config.early_exit && self.sets.sets() == 1 {
+                break;
+            }
+            iterations += 1;
+            if self.sets.union(u, v) {
+                self.forest[u].push((v, w));
+                self.forest[v].push((u, w));
+            }
+        }
+
+        root_forest(buffers, &self.forest, source);
+
+        let mut result = finalize_mst(buffers, iterations, n);
+        result.is_connected = self.sets.sets() == 1;
+        result
+    }
+}
@@ -0,0 +1,18 @@
+/// Configuration for Kruskal's MST algorithm.
+#[derive(Clone, Debug)]
+pub struct KruskalConfig {
+    pub early_exit: bool, // Stop scanning once the forest is a single tree
+}
+
+impl Default for KruskalConfig {
+    fn default() -> Self {
+        Self { early_exit: true }
+    }
+}
+
+impl KruskalConfig {
+    pub fn without_early_exit(mut self) -> Self {
+        self.early_exit = false;
+        self
+    }
+}
@@ -0,0 +1,24 @@
+mod algo;
+mod config;
+
+pub use algo::Kruskal;
+pub use config::KruskalConfig;
+
+use crate::algorithms::{MstAlgorithm, MstResult};
+use crate::utils::{FloatNumber, Graph, MstBuffers};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim};
+
+/// One-shot Kruskal's MST execute. Covers every component; `source` roots its own tree.
+pub fn cheeky_kruskal<T, N, G>(
+    graph: &G,
+    source: usize,
+    buffers: &mut MstBuffers<T, N>,
+) -> MstResult<T>
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T>,
+    DefaultAllocator: Allocator<N>,
+{
+    Kruskal::<T>::new().run(graph, source, buffers)
+}
@@ -1,17 +1,47 @@
 pub mod astar;
 pub mod bellman_ford;
+pub mod bidirectional;
+pub mod boruvka;
 mod common;
+pub mod contraction;
+pub mod delta_stepping;
 pub mod dijkstra;
+mod error;
 pub mod floyd_warshall;
 pub mod heaps;
+pub mod hybrid;
+pub mod johnson;
+pub mod kruskal;
 pub mod prim;
+pub mod spfa;
+pub mod yen;
 
 pub use astar::{
-    astar_with, cheeky_astar, AStar, AStarConfig, FnHeuristic, Heuristic, ZeroHeuristic,
+    astar_with, cheeky_astar, AStar, AStarConfig, EuclideanHeuristic, FnHeuristic, Heuristic,
+    LandmarkConfig, LandmarkHeuristic, LandmarkSelection, ManhattanHeuristic, OctileHeuristic,
+    ZeroHeuristic,
 };
 pub use bellman_ford::{bellman_ford_to, cheeky_bellman_ford, BellmanFord, BellmanFordConfig};
+pub use bidirectional::{
+    cheeky_bidirectional_dijkstra, BidirectionalDijkstra, BidirectionalDijkstraConfig,
+    BidirectionalResult,
+};
+pub use boruvka::{cheeky_boruvka, Boruvka, BoruvkaConfig};
 pub use common::*;
+pub use contraction::{ChPath, ChQuery, ContractionConfig, ContractionHierarchy};
+pub use delta_stepping::{
+    cheeky_delta_stepping, delta_stepping_to, Delta, DeltaStepping, DeltaSteppingConfig,
+};
 pub use dijkstra::{cheeky_dijkstra, dijkstra_to, Dijkstra, DijkstraConfig};
+pub use error::SsspError;
 pub use floyd_warshall::{cheeky_floyd_warshall, FloydWarshall, FloydWarshallConfig};
-pub use heaps::{BinaryHeap, FibonacciHeap, HeapEntry, PairingHeap, PriorityQueue};
+pub use heaps::{
+    AddressablePriorityQueue, BinaryHeap, BucketQueue, DaryHeap, FibHandle, FibonacciHeap,
+    HeapEntry, IndexedBinaryHeap, PairingHeap, PriorityQueue, RadixHeap,
+};
+pub use hybrid::{cheeky_hybrid, hybrid_to, Hybrid, HybridConfig};
+pub use johnson::{cheeky_johnson, Johnson, JohnsonConfig};
+pub use kruskal::{cheeky_kruskal, Kruskal, KruskalConfig};
 pub use prim::{cheeky_prim, Prim, PrimConfig};
+pub use spfa::{cheeky_spfa, Spfa, SpfaConfig};
+pub use yen::{k_shortest_paths, KPath};
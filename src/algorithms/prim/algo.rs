@@ -80,13 +80,9 @@ where
             let u = entry.vertex;
             let key_u = entry.dist;
 
-            // Skip if in MST alrdy (lazy deletion)
-            if buffers.in_mst[u] && self.config.lazy_deletion {
-                continue;
-            }
-
-            // Skip stale
-            if self.config.lazy_deletion && key_u > buffers.key[u] {
+            // Skip if in MST alrdy, or stale (lazy deletion)
+            let skip_stale = self.config.lazy_deletion || !H::DECREASE_KEY;
+            if skip_stale && (buffers.in_mst[u] || key_u > buffers.key[u]) {
                 continue;
             }
 
@@ -99,7 +95,7 @@ where
                 if !buffers.in_mst[v] && w < buffers.key[v] {
                     buffers.key[v] = w;
                     buffers.parent[v] = u;
-                    self.heap.push(w, v);
+                    self.heap.decrease_key(w, v);
                 }
             });
         }
@@ -1,7 +1,7 @@
 /// Configuration for Prim's MST algorithm.
 #[derive(Clone, Debug)]
 pub struct PrimConfig {
-    pub lazy_deletion: bool, // Skip stale entries in heap
+    pub lazy_deletion: bool, // Skip stale heap entries (forced on unless H::DECREASE_KEY)
 }
 
 impl Default for PrimConfig {
@@ -0,0 +1,154 @@
+use crate::algorithms::{finalize_sssp, init_sssp, SsspAlgorithm, SsspAlgorithmInfo, SsspResult};
+use crate::utils::{FloatNumber, Graph, SsspBuffers};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim};
+use std::collections::VecDeque;
+
+use super::config::SpfaConfig;
+
+/// Queue-based Bellman-Ford (Shortest Path Faster Algorithm).
+#[derive(Debug)]
+pub struct Spfa<T: FloatNumber> {
+    config: SpfaConfig,
+    queue: VecDeque<usize>,
+    in_queue: Vec<bool>,
+    hops: Vec<usize>, // Edges on current tree path from source
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: FloatNumber> Spfa<T> {
+    pub fn new() -> Self {
+        Self::with_config(SpfaConfig::default())
+    }
+
+    pub fn with_config(config: SpfaConfig) -> Self {
+        Self {
+            config,
+            queue: VecDeque::new(),
+            in_queue: Vec::new(),
+            hops: Vec::new(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    pub fn config(&self) -> &SpfaConfig {
+        &self.config
+    }
+
+    pub fn config_mut(&mut self) -> &mut SpfaConfig {
+        &mut self.config
+    }
+}
+
+impl<T: FloatNumber> Default for Spfa<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FloatNumber> SsspAlgorithmInfo for Spfa<T> {
+    fn name(&self) -> &'static str {
+        "SPFA"
+    }
+
+    fn supports_negative_weights(&self) -> bool {
+        true
+    }
+}
+
+impl<T, N, G> SsspAlgorithm<T, N, G> for Spfa<T>
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T>,
+    DefaultAllocator: Allocator<N>,
+{
+    fn run(&mut self, graph: &G, source: usize, buffers: &mut SsspBuffers<T, N>) -> SsspResult<T> {
+        debug_assert!(source < graph.n(), "Source vertex out of bounds");
+
+        init_sssp(buffers, source);
+
+        let n = graph.n();
+        self.queue.clear();
+        self.in_queue.clear();
+        self.in_queue.resize(n, false);
+        self.hops.clear();
+        self.hops.resize(n, 0);
+
+        self.queue.push_back(source);
+        self.in_queue[source] = true;
+        let mut queued_sum = T::zero(); // Sum of queued distances, for LLL
+
+        let mut iterations = 0usize;
+        let mut negative_cycle = false;
+
+        while !self.queue.is_empty() {
+            if self.config.lll {
+                rotate_large_labels(&mut self.queue, buffers.dist.as_slice(), queued_sum);
+            }
+
+            let u = self.queue.pop_front().unwrap();
+            self.in_queue[u] = false;
+            let d_u = buffers.dist[u];
+            queued_sum -= d_u;
+            iterations += 1;
+
+            let hops_u = self.hops[u];
+            graph.for_each_out_edge(u, |v, w| {
+                if negative_cycle {
+                    return;
+                }
+
+                let new_dist = d_u + w;
+                if new_dist >= buffers.dist[v] {
+                    return;
+                }
+
+                let old_dist = buffers.dist[v];
+                buffers.dist[v] = new_dist;
+                buffers.parent[v] = u;
+
+                // A simple path has at most n - 1 edges
+                self.hops[v] = hops_u + 1;
+                if self.hops[v] >= n {
+                    negative_cycle = true;
+                    return;
+                }
+
+                if self.in_queue[v] {
+                    queued_sum += new_dist - old_dist;
+                    return;
+                }
+
+                let front_smaller = self
+                    .queue
+                    .front()
+                    .is_some_and(|&f| new_dist < buffers.dist[f]);
+                if self.config.slf && front_smaller {
+                    self.queue.push_front(v);
+                } else {
+                    self.queue.push_back(v);
+                }
+                self.in_queue[v] = true;
+                queued_sum += new_dist;
+            });
+
+            if negative_cycle {
+                break;
+            }
+        }
+
+        finalize_sssp(buffers, iterations, negative_cycle)
+    }
+}
+
+/// LLL: move heads whose label exceeds the queue mean to the back.
+fn rotate_large_labels<T: FloatNumber>(queue: &mut VecDeque<usize>, dist: &[T], sum: T) {
+    let len = queue.len();
+    let mean = sum / T::from(len).unwrap();
+    for _ in 0..len {
+        match queue.front() {
+            Some(&f) if dist[f] > mean => queue.rotate_left(1),
+            _ => break,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::algorithms::{HasSsspConfig, SsspConfig};
+
+#[derive(Clone, Debug, Default)]
+pub struct SpfaConfig {
+    base: SsspConfig, // No target: labels are only final once the queue empties
+    pub slf: bool,    // Small Label First: push improved vertex to front if below queue head
+    pub lll: bool,    // Large Label Last: rotate heads above queue mean to back
+}
+
+impl SpfaConfig {
+    pub fn with_slf(mut self) -> Self {
+        self.slf = true;
+        self
+    }
+
+    pub fn with_lll(mut self) -> Self {
+        self.lll = true;
+        self
+    }
+}
+
+impl HasSsspConfig for SpfaConfig {
+    fn sssp_config(&self) -> &SsspConfig {
+        &self.base
+    }
+}
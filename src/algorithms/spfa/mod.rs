@@ -0,0 +1,24 @@
+mod algo;
+mod config;
+
+pub use algo::Spfa;
+pub use config::SpfaConfig;
+
+use crate::algorithms::{SsspAlgorithm, SsspResult};
+use crate::utils::{FloatNumber, Graph, SsspBuffers};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim};
+
+/// One-shot SPFA execute.
+pub fn cheeky_spfa<T, N, G>(
+    graph: &G,
+    source: usize,
+    buffers: &mut SsspBuffers<T, N>,
+) -> SsspResult<T>
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T>,
+    DefaultAllocator: Allocator<N>,
+{
+    Spfa::<T>::new().run(graph, source, buffers)
+}
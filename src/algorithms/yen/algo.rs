@@ -0,0 +1,176 @@
+use crate::algorithms::heaps::{BinaryHeap, HeapEntry};
+use crate::algorithms::{Dijkstra, DijkstraConfig, SsspAlgorithm};
+use crate::utils::{FloatNumber, Graph, SsspBuffers};
+use nalgebra::Dyn;
+use std::collections::HashSet;
+
+/// A loopless source-target path and its cost.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KPath<T: FloatNumber> {
+    pub cost: T,
+    pub path: Vec<usize>,
+}
+
+/// Up to `k` loopless source -> target paths, cheapest first.
+/// The first path is whichever shortest path Dijkstra finds; after it, each step
+/// takes the cheapest candidate, equal costs broken by lexicographic vertex order.
+/// Requires non-negative weights (spur searches use Dijkstra).
+pub fn k_shortest_paths<T, G>(graph: &G, source: usize, target: usize, k: usize) -> Vec<KPath<T>>
+where
+    T: FloatNumber,
+    G: Graph<T>,
+{
+    debug_assert!(source < graph.n(), "Source vertex out of bounds");
+    debug_assert!(target < graph.n(), "Target vertex out of bounds");
+
+    let mut masked = Masked::new(graph);
+    let mut buffers = SsspBuffers::new_inf(Dyn(graph.n()));
+    let mut dijkstra =
+        Dijkstra::<T, BinaryHeap<T>>::with_config(DijkstraConfig::with_target(target));
+
+    let mut found: Vec<KPath<T>> = Vec::new();
+    if k == 0 {
+        return found;
+    }
+    match spur_path(&masked, &mut dijkstra, &mut buffers, source, target) {
+        Some(first) => found.push(first),
+        None => return found,
+    }
+
+    let mut candidates: Vec<KPath<T>> = Vec::new();
+    let mut seen: HashSet<Vec<usize>> = HashSet::new();
+    seen.insert(found[0].path.clone());
+
+    while found.len() < k {
+        let prev = found.last().unwrap().path.clone();
+        let mut root_cost = T::zero();
+
+        for i in 0..prev.len() - 1 {
+            let spur = prev[i];
+            let root = &prev[..=i];
+
+            // Block every known continuation of this root, and the root itself
+            masked.clear();
+            for p in &found {
+                if p.path.len() > i + 1 && p.path[..=i] == *root {
+                    masked.remove_edge(p.path[i], p.path[i + 1]);
+                }
+            }
+            for &v in &root[..i] {
+                masked.remove_vertex(v);
+            }
+
+            if let Some(tail) = spur_path(&masked, &mut dijkstra, &mut buffers, spur, target) {
+                let mut path = root[..i].to_vec();
+                path.extend(tail.path);
+                if seen.insert(path.clone()) {
+                    candidates.push(KPath {
+                        cost: root_cost + tail.cost,
+                        path,
+                    });
+                }
+            }
+
+            root_cost += edge_weight(graph, spur, prev[i + 1]);
+        }
+
+        // Cheapest candidate, ties broken by vertex sequence
+        let Some(best) = (0..candidates.len()).min_by(|&a, &b| {
+            let (a, b) = (&candidates[a], &candidates[b]);
+            HeapEntry::cmp_dist(a.cost, b.cost).then_with(|| a.path.cmp(&b.path))
+        }) else {
+            break;
+        };
+        found.push(candidates.swap_remove(best));
+    }
+
+    found
+}
+
+/// Shortest `from` -> `target` path on the masked graph.
+fn spur_path<T, G>(
+    graph: &Masked<'_, G>,
+    dijkstra: &mut Dijkstra<T, BinaryHeap<T>>,
+    buffers: &mut SsspBuffers<T, Dyn>,
+    from: usize,
+    target: usize,
+) -> Option<KPath<T>>
+where
+    T: FloatNumber,
+    G: Graph<T>,
+{
+    dijkstra.run(graph, from, buffers);
+    let path = buffers.path_to(target)?;
+    Some(KPath {
+        cost: buffers.dist[target],
+        path,
+    })
+}
+
+/// Cheapest parallel edge (u -> v).
+fn edge_weight<T: FloatNumber, G: Graph<T>>(graph: &G, u: usize, v: usize) -> T {
+    let mut best = T::infinity();
+    graph.for_each_out_edge(u, |to, w| {
+        if to == v && w < best {
+            best = w;
+        }
+    });
+    best
+}
+
+/// Graph view with some vertices and edges temporarily removed.
+struct Masked<'a, G> {
+    graph: &'a G,
+    removed_vertices: Vec<bool>,
+    touched: Vec<usize>,
+    removed_edges: HashSet<(usize, usize)>,
+}
+
+impl<'a, G> Masked<'a, G> {
+    fn new<T: FloatNumber>(graph: &'a G) -> Self
+    where
+        G: Graph<T>,
+    {
+        Self {
+            graph,
+            removed_vertices: vec![false; graph.n()],
+            touched: Vec::new(),
+            removed_edges: HashSet::new(),
+        }
+    }
+
+    fn clear(&mut self) {
+        for &v in &self.touched {
+            self.removed_vertices[v] = false;
+        }
+        self.touched.clear();
+        self.removed_edges.clear();
+    }
+
+    fn remove_vertex(&mut self, v: usize) {
+        self.removed_vertices[v] = true;
+        self.touched.push(v);
+    }
+
+    /// Removes all parallel copies of (u -> v).
+    fn remove_edge(&mut self, u: usize, v: usize) {
+        self.removed_edges.insert((u, v));
+    }
+}
+
+impl<T: FloatNumber, G: Graph<T>> Graph<T> for Masked<'_, G> {
+    fn n(&self) -> usize {
+        self.graph.n()
+    }
+
+    fn for_each_out_edge<F: FnMut(usize, T)>(&self, u: usize, mut f: F) {
+        if self.removed_vertices[u] {
+            return;
+        }
+        self.graph.for_each_out_edge(u, |v, w| {
+            if !self.removed_vertices[v] && !self.removed_edges.contains(&(u, v)) {
+                f(v, w);
+            }
+        });
+    }
+}
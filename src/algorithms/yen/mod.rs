@@ -0,0 +1,5 @@
+//! Yen's k-shortest loopless paths. See `docs/algorithms/yen.md`.
+
+mod algo;
+
+pub use algo::{k_shortest_paths, KPath};
@@ -0,0 +1,287 @@
+//! 9th DIMACS shortest-path challenge formats: `.gr` arcs, `.co` coordinates,
+//! `.ss` / `.p2p` queries. Files use 1-based ids; everything here is 0-based.
+
+use crate::io::{parse_weight, FormatError};
+use crate::utils::{AdjListGraph, FloatNumber, Graph};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::str::SplitWhitespace;
+
+/// Arc file (`p sp n m`, `a u v w`) into a directed graph.
+pub fn read_gr<T: FloatNumber, R: Read>(reader: R) -> Result<AdjListGraph<T>, FormatError> {
+    let mut graph: Option<AdjListGraph<T>> = None;
+    let mut expected = 0usize;
+    let mut arcs = 0usize;
+
+    for_each_line(reader, |line, tag, fields| {
+        match tag {
+            "p" => {
+                if graph.is_some() {
+                    return Err(FormatError::parse(line, "duplicate problem line"));
+                }
+                expect_word(line, fields, "sp")?;
+                let n = count(line, fields, "vertex count")?;
+                expected = count(line, fields, "arc count")?;
+                graph = Some(AdjListGraph::try_new(n).ok_or_else(|| too_large(line, n))?);
+            }
+            "a" => {
+                let g = graph
+                    .as_mut()
+                    .ok_or_else(|| FormatError::parse(line, "arc before problem line"))?;
+                let u = vertex(line, fields, g.n())?;
+                let v = vertex(line, fields, g.n())?;
+                let w = weight(line, fields)?;
+                g.add_edge(u, v, w);
+                arcs += 1;
+            }
+            _ => return Err(unknown(line, tag)),
+        }
+        end_of_line(line, fields)
+    })?;
+
+    let graph = graph.ok_or_else(|| FormatError::Invalid("missing problem line".into()))?;
+    if arcs != expected {
+        return Err(FormatError::Invalid(format!(
+            "expected {expected} arcs, found {arcs}"
+        )));
+    }
+    Ok(graph)
+}
+
+/// Coordinate file (`p aux sp co n`, `v id x y`), indexed by vertex.
+pub fn read_co<T: FloatNumber, R: Read>(reader: R) -> Result<Vec<(T, T)>, FormatError> {
+    let mut coords: Option<Vec<Option<(T, T)>>> = None;
+
+    for_each_line(reader, |line, tag, fields| {
+        match tag {
+            "p" => {
+                if coords.is_some() {
+                    return Err(FormatError::parse(line, "duplicate problem line"));
+                }
+                expect_words(line, fields, &["aux", "sp", "co"])?;
+                let n = count(line, fields, "vertex count")?;
+                let mut table = Vec::new();
+                table.try_reserve_exact(n).map_err(|_| too_large(line, n))?;
+                table.resize(n, None);
+                coords = Some(table);
+            }
+            "v" => {
+                let table = coords
+                    .as_mut()
+                    .ok_or_else(|| FormatError::parse(line, "vertex before problem line"))?;
+                let v = vertex(line, fields, table.len())?;
+                let x = weight(line, fields)?;
+                let y = weight(line, fields)?;
+                table[v] = Some((x, y));
+            }
+            _ => return Err(unknown(line, tag)),
+        }
+        end_of_line(line, fields)
+    })?;
+
+    let coords = coords.ok_or_else(|| FormatError::Invalid("missing problem line".into()))?;
+    coords
+        .into_iter()
+        .enumerate()
+        .map(|(v, xy)| {
+            xy.ok_or_else(|| FormatError::Invalid(format!("vertex {} has no coordinates", v + 1)))
+        })
+        .collect()
+}
+
+/// Single-source query file (`p aux sp ss k`, `s id`).
+pub fn read_ss<R: Read>(reader: R, n: usize) -> Result<Vec<usize>, FormatError> {
+    let mut sources = Vec::new();
+    read_queries(reader, "ss", |line, tag, fields| {
+        if tag != "s" {
+            return Err(unknown(line, tag));
+        }
+        sources.push(vertex(line, fields, n)?);
+        Ok(())
+    })?;
+    Ok(sources)
+}
+
+/// Point-to-point query file (`p aux sp p2p k`, `q s t`).
+pub fn read_p2p<R: Read>(reader: R, n: usize) -> Result<Vec<(usize, usize)>, FormatError> {
+    let mut pairs = Vec::new();
+    read_queries(reader, "p2p", |line, tag, fields| {
+        if tag != "q" {
+            return Err(unknown(line, tag));
+        }
+        let s = vertex(line, fields, n)?;
+        let t = vertex(line, fields, n)?;
+        pairs.push((s, t));
+        Ok(())
+    })?;
+    Ok(pairs)
+}
+
+/// Write any graph as a `.gr` arc file.
+pub fn write_gr<T, G, W>(graph: &G, mut writer: W) -> std::io::Result<()>
+where
+    T: FloatNumber,
+    G: Graph<T>,
+    W: Write,
+{
+    let n = graph.n();
+    let mut m = 0usize;
+    for u in 0..n {
+        graph.for_each_out_edge(u, |_, _| m += 1);
+    }
+
+    writeln!(writer, "p sp {n} {m}")?;
+    let mut result = Ok(());
+    for u in 0..n {
+        graph.for_each_out_edge(u, |v, w| {
+            if result.is_ok() {
+                result = writeln!(writer, "a {} {} {}", u + 1, v + 1, w.to_f64().unwrap());
+            }
+        });
+    }
+    result
+}
+
+/// Write a coordinate table as a `.co` file.
+pub fn write_co<T: FloatNumber, W: Write>(coords: &[(T, T)], mut writer: W) -> std::io::Result<()> {
+    writeln!(writer, "p aux sp co {}", coords.len())?;
+    for (v, &(x, y)) in coords.iter().enumerate() {
+        writeln!(
+            writer,
+            "v {} {} {}",
+            v + 1,
+            x.to_f64().unwrap(),
+            y.to_f64().unwrap()
+        )?;
+    }
+    Ok(())
+}
+
+/// Query files: `p aux sp <kind> k`, then k query lines.
+fn read_queries<R, F>(reader: R, kind: &str, mut on_query: F) -> Result<(), FormatError>
+where
+    R: Read,
+    F: FnMut(usize, &str, &mut SplitWhitespace<'_>) -> Result<(), FormatError>,
+{
+    let mut expected: Option<usize> = None;
+    let mut found = 0usize;
+
+    for_each_line(reader, |line, tag, fields| {
+        if tag == "p" {
+            if expected.is_some() {
+                return Err(FormatError::parse(line, "duplicate problem line"));
+            }
+            expect_words(line, fields, &["aux", "sp", kind])?;
+            expected = Some(count(line, fields, "query count")?);
+        } else {
+            if expected.is_none() {
+                return Err(FormatError::parse(line, "query before problem line"));
+            }
+            on_query(line, tag, fields)?;
+            found += 1;
+        }
+        end_of_line(line, fields)
+    })?;
+
+    match expected {
+        None => Err(FormatError::Invalid("missing problem line".into())),
+        Some(k) if k != found => Err(FormatError::Invalid(format!(
+            "expected {k} queries, found {found}"
+        ))),
+        Some(_) => Ok(()),
+    }
+}
+
+/// Calls `f(line_number, tag, rest)` per non-empty, non-comment line.
+fn for_each_line<R, F>(reader: R, mut f: F) -> Result<(), FormatError>
+where
+    R: Read,
+    F: FnMut(usize, &str, &mut SplitWhitespace<'_>) -> Result<(), FormatError>,
+{
+    for (i, text) in BufReader::new(reader).lines().enumerate() {
+        let text = text?;
+        let mut fields = text.split_whitespace();
+        match fields.next() {
+            None | Some("c") => continue,
+            Some(tag) => f(i + 1, tag, &mut fields)?,
+        }
+    }
+    Ok(())
+}
+
+/// Header vertex count that can't be allocated.
+fn too_large(line: usize, n: usize) -> FormatError {
+    FormatError::parse(line, format!("vertex count {n} too large"))
+}
+
+fn unknown(line: usize, tag: &str) -> FormatError {
+    FormatError::parse(line, format!("unknown line type '{tag}'"))
+}
+
+fn field<'a>(
+    line: usize,
+    fields: &mut SplitWhitespace<'a>,
+    what: &str,
+) -> Result<&'a str, FormatError> {
+    fields
+        .next()
+        .ok_or_else(|| FormatError::parse(line, format!("missing {what}")))
+}
+
+fn expect_word(
+    line: usize,
+    fields: &mut SplitWhitespace<'_>,
+    word: &str,
+) -> Result<(), FormatError> {
+    let found = field(line, fields, &format!("'{word}'"))?;
+    if found != word {
+        return Err(FormatError::parse(
+            line,
+            format!("expected '{word}', found '{found}'"),
+        ));
+    }
+    Ok(())
+}
+
+fn expect_words(
+    line: usize,
+    fields: &mut SplitWhitespace<'_>,
+    words: &[&str],
+) -> Result<(), FormatError> {
+    words.iter().try_for_each(|w| expect_word(line, fields, w))
+}
+
+fn count(line: usize, fields: &mut SplitWhitespace<'_>, what: &str) -> Result<usize, FormatError> {
+    let text = field(line, fields, what)?;
+    text.parse()
+        .map_err(|_| FormatError::parse(line, format!("invalid {what} '{text}'")))
+}
+
+/// 1-based id in 1..=n, returned 0-based.
+fn vertex(line: usize, fields: &mut SplitWhitespace<'_>, n: usize) -> Result<usize, FormatError> {
+    let text = field(line, fields, "vertex id")?;
+    match text.parse::<usize>() {
+        Ok(id) if (1..=n).contains(&id) => Ok(id - 1),
+        Ok(id) => Err(FormatError::parse(
+            line,
+            format!("vertex {id} out of range 1..={n}"),
+        )),
+        Err(_) => Err(FormatError::parse(
+            line,
+            format!("invalid vertex id '{text}'"),
+        )),
+    }
+}
+
+fn weight<T: FloatNumber>(line: usize, fields: &mut SplitWhitespace<'_>) -> Result<T, FormatError> {
+    parse_weight(line, field(line, fields, "number")?)
+}
+
+fn end_of_line(line: usize, fields: &mut SplitWhitespace<'_>) -> Result<(), FormatError> {
+    match fields.next() {
+        None => Ok(()),
+        Some(extra) => Err(FormatError::parse(
+            line,
+            format!("unexpected trailing field '{extra}'"),
+        )),
+    }
+}
@@ -0,0 +1,221 @@
+//! Graphviz DOT export, with optional highlighting of shortest-path trees,
+//! paths and spanning trees.
+
+use crate::utils::{FloatNumber, Graph, MstBuffers, SsspBuffers, PARENT_NONE};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+const TREE_STYLE: &str = "color=\"blue\", penwidth=2";
+const PATH_STYLE: &str = "color=\"red\", penwidth=3";
+
+/// DOT renderer for a graph plus optional overlays.
+#[derive(Clone, Debug)]
+pub struct Dot<'a, T: FloatNumber, G> {
+    graph: &'a G,
+    undirected: bool,
+    labels: Option<Vec<T>>,    // Shown under the vertex id
+    tree: Vec<(usize, usize)>, // Highlighted u -> v
+    path: Vec<usize>,          // Highlighted vertex sequence
+}
+
+impl<'a, T: FloatNumber, G: Graph<T>> Dot<'a, T, G> {
+    pub fn new(graph: &'a G) -> Self {
+        Self {
+            graph,
+            undirected: false,
+            labels: None,
+            tree: Vec::new(),
+            path: Vec::new(),
+        }
+    }
+
+    /// Draw `u -- v` once per pair (u <= v) instead of arcs; for symmetric graphs.
+    pub fn undirected(mut self) -> Self {
+        self.undirected = true;
+        self
+    }
+
+    /// Distances as vertex labels and the parent tree highlighted.
+    pub fn with_sssp<N>(mut self, buffers: &SsspBuffers<T, N>) -> Self
+    where
+        N: Dim,
+        DefaultAllocator: Allocator<N>,
+    {
+        self.labels = Some(buffers.dist.iter().copied().collect());
+        self.tree = (0..buffers.parent.len())
+            .filter(|&v| buffers.parent[v] != PARENT_NONE)
+            .map(|v| (buffers.parent[v], v))
+            .collect();
+        self
+    }
+
+    /// Spanning-tree edges highlighted.
+    pub fn with_mst<N>(mut self, buffers: &MstBuffers<T, N>) -> Self
+    where
+        N: Dim,
+        DefaultAllocator: Allocator<N>,
+    {
+        self.tree = buffers
+            .collect_edges()
+            .iter()
+            .map(|e| (e.from, e.to))
+            .collect();
+        self
+    }
+
+    /// Vertex labels, e.g. one row of `ApspBuffers`.
+    pub fn with_labels(mut self, labels: Vec<T>) -> Self {
+        debug_assert!(labels.len() == self.graph.n(), "One label per vertex");
+        self.labels = Some(labels);
+        self
+    }
+
+    /// A path (from `path_to` or `ApspBuffers::path`) drawn over everything else.
+    pub fn with_path(mut self, path: &[usize]) -> Self {
+        self.path = path.to_vec();
+        self
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let (kind, arrow) = if self.undirected {
+            ("graph", "--")
+        } else {
+            ("digraph", "->")
+        };
+
+        let on_path = pairs(&self.path, self.undirected);
+        let in_tree = pairs_of(&self.tree, self.undirected);
+        let mut path_left = self.cheapest(&on_path);
+        let mut tree_left = self.cheapest(&in_tree);
+
+        let mut marked = vec![false; self.graph.n()];
+        self.path.iter().for_each(|&v| marked[v] = true);
+
+        let _ = writeln!(out, "{kind} G {{");
+        let _ = writeln!(out, "  node [shape=circle];");
+        for v in 0..self.graph.n() {
+            let label = match &self.labels {
+                Some(labels) => format!("{v}\\n{}", number(labels[v])),
+                None => v.to_string(),
+            };
+            let style = if marked[v] { ", color=\"red\"" } else { "" };
+            let _ = writeln!(out, "  {v} [label=\"{label}\"{style}];");
+        }
+
+        for u in 0..self.graph.n() {
+            self.graph.for_each_out_edge(u, |v, w| {
+                if self.undirected && v < u {
+                    return;
+                }
+                // Parallel edges: only the cheapest copy is highlighted
+                let key = key(u, v, self.undirected);
+                let style = if take(&mut path_left, key, w) {
+                    format!(", {PATH_STYLE}")
+                } else if take(&mut tree_left, key, w) {
+                    format!(", {TREE_STYLE}")
+                } else {
+                    String::new()
+                };
+                let _ = writeln!(out, "  {u} {arrow} {v} [label=\"{}\"{style}];", number(w));
+            });
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    pub fn write<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(self.render().as_bytes())
+    }
+
+    /// Minimum weight per highlighted pair.
+    fn cheapest(&self, keys: &[(usize, usize)]) -> HashMap<(usize, usize), T> {
+        let mut best: HashMap<(usize, usize), T> =
+            keys.iter().map(|&k| (k, T::infinity())).collect();
+        for u in 0..self.graph.n() {
+            self.graph.for_each_out_edge(u, |v, w| {
+                if let Some(b) = best.get_mut(&key(u, v, self.undirected)) {
+                    if w < *b {
+                        *b = w;
+                    }
+                }
+            });
+        }
+        best
+    }
+}
+
+/// Plain graph with edge weights.
+pub fn to_dot<T: FloatNumber, G: Graph<T>>(graph: &G) -> String {
+    Dot::new(graph).render()
+}
+
+/// Shortest-path tree with distance labels; the path to `target` on top.
+pub fn sssp_to_dot<T, N, G>(graph: &G, buffers: &SsspBuffers<T, N>, target: Option<usize>) -> String
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T>,
+    DefaultAllocator: Allocator<N>,
+{
+    let mut dot = Dot::new(graph).with_sssp(buffers);
+    if let Some(path) = target.and_then(|t| buffers.path_to(t)) {
+        dot = dot.with_path(&path);
+    }
+    dot.render()
+}
+
+/// Undirected drawing with the spanning tree (or forest) highlighted.
+pub fn mst_to_dot<T, N, G>(graph: &G, buffers: &MstBuffers<T, N>) -> String
+where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T>,
+    DefaultAllocator: Allocator<N>,
+{
+    Dot::new(graph).undirected().with_mst(buffers).render()
+}
+
+/// Graph with one path highlighted.
+pub fn path_to_dot<T: FloatNumber, G: Graph<T>>(graph: &G, path: &[usize]) -> String {
+    Dot::new(graph).with_path(path).render()
+}
+
+#[inline]
+fn key(u: usize, v: usize, undirected: bool) -> (usize, usize) {
+    if undirected && v < u {
+        (v, u)
+    } else {
+        (u, v)
+    }
+}
+
+fn pairs(path: &[usize], undirected: bool) -> Vec<(usize, usize)> {
+    path.windows(2)
+        .map(|p| key(p[0], p[1], undirected))
+        .collect()
+}
+
+fn pairs_of(edges: &[(usize, usize)], undirected: bool) -> Vec<(usize, usize)> {
+    edges.iter().map(|&(u, v)| key(u, v, undirected)).collect()
+}
+
+/// Highlight this edge if it is the cheapest unclaimed copy of its pair.
+fn take<T: FloatNumber>(left: &mut HashMap<(usize, usize), T>, key: (usize, usize), w: T) -> bool {
+    match left.get(&key) {
+        Some(&best) if w == best => {
+            left.remove(&key);
+            true
+        }
+        _ => false,
+    }
+}
+
+fn number<T: FloatNumber>(x: T) -> String {
+    if x.is_infinite() {
+        if x > T::zero() { "inf" } else { "-inf" }.to_string()
+    } else {
+        x.to_f64().unwrap().to_string()
+    }
+}
@@ -0,0 +1,77 @@
+//! Plain edge lists (SNAP style): one `u v [w]` per line, separated by
+//! whitespace and/or commas. Lines starting with `#` or `%` are comments.
+
+use crate::io::{parse_id, parse_weight, FormatError, IdMap, LoadedGraph, ReadOptions};
+use crate::utils::{FloatNumber, Graph};
+use std::io::{BufRead, BufReader, Read, Write};
+
+/// Read an edge list with 0-based ids (or any ids, with `remap_ids`).
+/// Missing weights take `default_weight`.
+pub fn read_edge_list<T: FloatNumber, R: Read>(
+    reader: R,
+    options: &ReadOptions<T>,
+) -> Result<LoadedGraph<T>, FormatError> {
+    let mut ids = IdMap::new(0, options.remap_ids);
+    let mut edges = Vec::new();
+
+    for (i, text) in BufReader::new(reader).lines().enumerate() {
+        let text = text?;
+        let line = i + 1;
+        let trimmed = text.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with('%') {
+            continue;
+        }
+
+        let fields: Vec<&str> = trimmed
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|f| !f.is_empty())
+            .collect();
+        let (u, v, w) = match fields.as_slice() {
+            [u, v] => (*u, *v, None),
+            [u, v, w] => (*u, *v, Some(*w)),
+            [_] => return Err(FormatError::parse(line, "missing target vertex")),
+            _ => {
+                return Err(FormatError::parse(
+                    line,
+                    format!("expected 2 or 3 fields, found {}", fields.len()),
+                ))
+            }
+        };
+
+        let u = ids.vertex(parse_id(line, u)?);
+        let v = ids.vertex(parse_id(line, v)?);
+        let w = match w {
+            Some(text) => parse_weight(line, text)?,
+            None => options.default_weight,
+        };
+        edges.push((u, v, w));
+    }
+
+    let (mut graph, ids) = ids.finish(0)?;
+    for (u, v, w) in edges {
+        graph.add_edge(u, v, w);
+        if options.symmetric && u != v {
+            graph.add_edge(v, u, w);
+        }
+    }
+    Ok(LoadedGraph { graph, ids })
+}
+
+/// Write `u v w` lines (0-based), fields joined by `delimiter`.
+pub fn write_edge_list<T, G, W>(graph: &G, mut writer: W, delimiter: char) -> std::io::Result<()>
+where
+    T: FloatNumber,
+    G: Graph<T>,
+    W: Write,
+{
+    let mut result = Ok(());
+    for u in 0..graph.n() {
+        graph.for_each_out_edge(u, |v, w| {
+            if result.is_ok() {
+                let w = w.to_f64().unwrap();
+                result = writeln!(writer, "{u}{delimiter}{v}{delimiter}{w}");
+            }
+        });
+    }
+    result
+}
@@ -0,0 +1,173 @@
+//! Matrix Market coordinate files (SuiteSparse `.mtx`). Entry (i, j, w) is the
+//! edge i -> j. Ids are 1-based in the file and 0-based in memory.
+
+use crate::io::{parse_id, parse_weight, FormatError, IdMap, LoadedGraph, ReadOptions};
+use crate::utils::{FloatNumber, Graph};
+use std::io::{BufRead, BufReader, Read, Write};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Field {
+    Real,
+    Pattern,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+}
+
+/// Read a `matrix coordinate` file with `real`, `integer` or `pattern` entries
+/// and `general`, `symmetric` or `skew-symmetric` structure. Symmetric files
+/// store one triangle; the mirror edge is added (negated when skew).
+pub fn read_matrix_market<T: FloatNumber, R: Read>(
+    reader: R,
+    options: &ReadOptions<T>,
+) -> Result<LoadedGraph<T>, FormatError> {
+    let mut lines = BufReader::new(reader).lines().enumerate();
+
+    let (field, symmetry) = match lines.next() {
+        Some((_, text)) => parse_header(&text?)?,
+        None => return Err(FormatError::Invalid("empty file".into())),
+    };
+
+    let mut size: Option<(usize, usize)> = None; // (dimension, nnz)
+    let mut ids = IdMap::new(1, options.remap_ids);
+    let mut entries = Vec::new();
+
+    for (i, text) in lines {
+        let text = text?;
+        let line = i + 1;
+        let trimmed = text.trim();
+        if trimmed.is_empty() || trimmed.starts_with('%') {
+            continue;
+        }
+        let fields: Vec<&str> = trimmed.split_whitespace().collect();
+
+        let Some((dim, _)) = size else {
+            let [rows, cols, nnz] = fields.as_slice() else {
+                return Err(FormatError::parse(line, "expected 'rows cols entries'"));
+            };
+            let rows = parse_id(line, rows)?;
+            let cols = parse_id(line, cols)?;
+            size = Some((rows.max(cols), parse_id(line, nnz)?));
+            continue;
+        };
+
+        let expected = if field == Field::Pattern { 2 } else { 3 };
+        if fields.len() != expected {
+            return Err(FormatError::parse(
+                line,
+                format!("expected {expected} fields, found {}", fields.len()),
+            ));
+        }
+        let mut vertex = |text: &str| match parse_id(line, text)? {
+            id if (1..=dim).contains(&id) => Ok(ids.vertex(id)),
+            id => Err(FormatError::parse(
+                line,
+                format!("index {id} out of range 1..={dim}"),
+            )),
+        };
+        let u = vertex(fields[0])?;
+        let v = vertex(fields[1])?;
+        let w = match field {
+            Field::Real => parse_weight(line, fields[2])?,
+            Field::Pattern => options.default_weight,
+        };
+        entries.push((u, v, w));
+    }
+
+    let Some((dim, nnz)) = size else {
+        return Err(FormatError::Invalid("missing size line".into()));
+    };
+    if entries.len() != nnz {
+        return Err(FormatError::Invalid(format!(
+            "expected {nnz} entries, found {}",
+            entries.len()
+        )));
+    }
+
+    let (mut graph, ids) = ids.finish(dim)?;
+    for (u, v, w) in entries {
+        graph.add_edge(u, v, w);
+        if u != v {
+            match symmetry {
+                Symmetry::General => {}
+                Symmetry::Symmetric => graph.add_edge(v, u, w),
+                Symmetry::SkewSymmetric => graph.add_edge(v, u, -w),
+            }
+        }
+    }
+    Ok(LoadedGraph { graph, ids })
+}
+
+/// Write any graph as a `real general` coordinate file.
+pub fn write_matrix_market<T, G, W>(graph: &G, mut writer: W) -> std::io::Result<()>
+where
+    T: FloatNumber,
+    G: Graph<T>,
+    W: Write,
+{
+    let n = graph.n();
+    let mut m = 0usize;
+    for u in 0..n {
+        graph.for_each_out_edge(u, |_, _| m += 1);
+    }
+
+    writeln!(writer, "%%MatrixMarket matrix coordinate real general")?;
+    writeln!(writer, "{n} {n} {m}")?;
+    let mut result = Ok(());
+    for u in 0..n {
+        graph.for_each_out_edge(u, |v, w| {
+            if result.is_ok() {
+                result = writeln!(writer, "{} {} {}", u + 1, v + 1, w.to_f64().unwrap());
+            }
+        });
+    }
+    result
+}
+
+fn parse_header(text: &str) -> Result<(Field, Symmetry), FormatError> {
+    let words: Vec<String> = text.split_whitespace().map(str::to_lowercase).collect();
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
+
+    let [banner, object, format, field, symmetry] = words.as_slice() else {
+        return Err(FormatError::parse(
+            1,
+            "expected '%%MatrixMarket matrix coordinate <field> <symmetry>'",
+        ));
+    };
+    if *banner != "%%matrixmarket" {
+        return Err(FormatError::parse(1, "missing '%%MatrixMarket' banner"));
+    }
+    if *object != "matrix" || *format != "coordinate" {
+        return Err(FormatError::parse(
+            1,
+            format!("unsupported layout '{object} {format}', need 'matrix coordinate'"),
+        ));
+    }
+
+    let field = match *field {
+        "real" | "integer" => Field::Real,
+        "pattern" => Field::Pattern,
+        other => {
+            return Err(FormatError::parse(
+                1,
+                format!("unsupported field '{other}'"),
+            ))
+        }
+    };
+    let symmetry = match *symmetry {
+        "general" => Symmetry::General,
+        "symmetric" => Symmetry::Symmetric,
+        "skew-symmetric" => Symmetry::SkewSymmetric,
+        other => {
+            return Err(FormatError::parse(
+                1,
+                format!("unsupported symmetry '{other}'"),
+            ))
+        }
+    };
+    Ok((field, symmetry))
+}
@@ -0,0 +1,172 @@
+pub mod dimacs;
+pub mod dot;
+pub mod edge_list;
+pub mod matrix_market;
+
+use crate::utils::{AdjListGraph, FloatNumber};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Options for the Matrix Market and edge-list readers.
+#[derive(Clone, Debug)]
+pub struct ReadOptions<T: FloatNumber> {
+    pub default_weight: T, // Pattern matrices, two-column edge lists
+    pub remap_ids: bool,   // Compact ids to 0..k in order of first appearance
+    pub symmetric: bool,   // Edge lists: also add v -> u (Matrix Market reads its header)
+}
+
+impl<T: FloatNumber> Default for ReadOptions<T> {
+    fn default() -> Self {
+        Self {
+            default_weight: T::one(),
+            remap_ids: false,
+            symmetric: false,
+        }
+    }
+}
+
+impl<T: FloatNumber> ReadOptions<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_default_weight(mut self, weight: T) -> Self {
+        self.default_weight = weight;
+        self
+    }
+
+    pub fn remapped(mut self) -> Self {
+        self.remap_ids = true;
+        self
+    }
+
+    pub fn symmetric(mut self) -> Self {
+        self.symmetric = true;
+        self
+    }
+}
+
+/// A graph read from a file, with each vertex's id as written there.
+#[derive(Clone, Debug)]
+pub struct LoadedGraph<T: FloatNumber> {
+    pub graph: AdjListGraph<T>,
+    pub ids: Vec<usize>, // ids[v] = file id of vertex v
+}
+
+/// File ids -> vertices: either `id - base`, or compacted in first-seen order.
+pub(crate) struct IdMap {
+    base: usize,
+    remap: bool,
+    index: HashMap<usize, usize>,
+    ids: Vec<usize>,
+    max: Option<usize>,
+}
+
+impl IdMap {
+    pub(crate) fn new(base: usize, remap: bool) -> Self {
+        Self {
+            base,
+            remap,
+            index: HashMap::new(),
+            ids: Vec::new(),
+            max: None,
+        }
+    }
+
+    /// Vertex for a file id (already checked to be >= base).
+    pub(crate) fn vertex(&mut self, id: usize) -> usize {
+        if self.remap {
+            *self.index.entry(id).or_insert_with(|| {
+                self.ids.push(id);
+                self.ids.len() - 1
+            })
+        } else {
+            self.max = self.max.max(Some(id));
+            id - self.base
+        }
+    }
+
+    /// Empty graph (at least `min_n` vertices without remapping) and the id table.
+    /// Fails instead of aborting when the ids imply an unallocatable vertex count.
+    pub(crate) fn finish<T: FloatNumber>(
+        self,
+        min_n: usize,
+    ) -> Result<(AdjListGraph<T>, Vec<usize>), FormatError> {
+        let too_large = || FormatError::Invalid("vertex count too large".into());
+        let ids = if self.remap {
+            self.ids
+        } else {
+            let seen = match self.max {
+                Some(m) => (m - self.base).checked_add(1).ok_or_else(too_large)?,
+                None => 0,
+            };
+            let n = seen.max(min_n);
+            let mut ids = Vec::new();
+            ids.try_reserve_exact(n).map_err(|_| too_large())?;
+            ids.extend((0..n).map(|v| v + self.base));
+            ids
+        };
+        let graph = AdjListGraph::try_new(ids.len()).ok_or_else(too_large)?;
+        Ok((graph, ids))
+    }
+}
+
+/// Failure reading a graph file.
+#[derive(Debug)]
+pub enum FormatError {
+    Io(std::io::Error),
+    /// Malformed content at a 1-based line number.
+    Parse {
+        line: usize,
+        message: String,
+    },
+    /// Whole-file problem, e.g. a count that doesn't match the header.
+    Invalid(String),
+}
+
+impl FormatError {
+    pub(crate) fn parse(line: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "I/O error: {e}"),
+            Self::Parse { line, message } => write!(f, "line {line}: {message}"),
+            Self::Invalid(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for FormatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Parse { .. } | Self::Invalid(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for FormatError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// Non-negative integer id.
+pub(crate) fn parse_id(line: usize, text: &str) -> Result<usize, FormatError> {
+    text.parse()
+        .map_err(|_| FormatError::parse(line, format!("invalid vertex id '{text}'")))
+}
+
+pub(crate) fn parse_weight<T: FloatNumber>(line: usize, text: &str) -> Result<T, FormatError> {
+    text.parse::<f64>()
+        .ok()
+        .and_then(T::from)
+        .ok_or_else(|| FormatError::parse(line, format!("invalid number '{text}'")))
+}
@@ -1,20 +1,42 @@
 pub mod algorithms;
+pub mod io;
 pub mod utils;
 
 pub use algorithms::{
-    astar_with, cheeky_astar, AStar, AStarConfig, FnHeuristic, Heuristic, ZeroHeuristic,
+    astar_with, cheeky_astar, AStar, AStarConfig, EuclideanHeuristic, FnHeuristic, Heuristic,
+    LandmarkConfig, LandmarkHeuristic, LandmarkSelection, ManhattanHeuristic, OctileHeuristic,
+    ZeroHeuristic,
 };
 pub use algorithms::{bellman_ford_to, cheeky_bellman_ford, BellmanFord, BellmanFordConfig};
+pub use algorithms::{
+    cheeky_bidirectional_dijkstra, BidirectionalDijkstra, BidirectionalDijkstraConfig,
+    BidirectionalResult,
+};
+pub use algorithms::{cheeky_boruvka, Boruvka, BoruvkaConfig};
+pub use algorithms::{
+    cheeky_delta_stepping, delta_stepping_to, Delta, DeltaStepping, DeltaSteppingConfig,
+};
 pub use algorithms::{cheeky_dijkstra, dijkstra_to, Dijkstra, DijkstraConfig};
 pub use algorithms::{cheeky_floyd_warshall, FloydWarshall, FloydWarshallConfig};
+pub use algorithms::{cheeky_hybrid, hybrid_to, Hybrid, HybridConfig};
+pub use algorithms::{cheeky_johnson, Johnson, JohnsonConfig};
+pub use algorithms::{cheeky_kruskal, Kruskal, KruskalConfig};
 pub use algorithms::{cheeky_prim, Prim, PrimConfig};
+pub use algorithms::{cheeky_spfa, Spfa, SpfaConfig};
+pub use algorithms::{k_shortest_paths, KPath};
+pub use algorithms::{
+    AddressablePriorityQueue, BinaryHeap, BucketQueue, DaryHeap, FibHandle, FibonacciHeap,
+    HeapEntry, IndexedBinaryHeap, PairingHeap, PriorityQueue, RadixHeap,
+};
 pub use algorithms::{ApspAlgorithm, ApspAlgorithmInfo, ApspResult};
-pub use algorithms::{BinaryHeap, FibonacciHeap, HeapEntry, PairingHeap, PriorityQueue};
+pub use algorithms::{ChPath, ChQuery, ContractionConfig, ContractionHierarchy};
 pub use algorithms::{MstAlgorithm, MstAlgorithmInfo, MstResult};
-pub use algorithms::{SsspAlgorithm, SsspAlgorithmInfo, SsspResult};
+pub use algorithms::{NegativeCycle, SsspAlgorithm, SsspAlgorithmInfo, SsspError, SsspResult};
 pub use utils::{
-    all_pairs_sssp, parallel_sssp, AdjListGraph, ApspBuffers, Edge, FloatNumber, Graph, MstBuffers,
-    MstEdge, MultiSourceResult, SsspBuffers, APSP_NO_PATH, MST_PARENT_NONE, PARENT_NONE,
+    all_pairs_sssp, parallel_sssp, AdjListGraph, ApspBuffers, BidirectionalGraph, Connectivity,
+    CsrGraph, DiagonalMovement, DisjointSet, Edge, FloatNumber, Graph, GridGraph, MstBuffers,
+    MstEdge, MultiSourceResult, ReverseGraph, SsspBuffers, UndirectedEdge, UndirectedGraph,
+    WithInEdges, APSP_NO_PATH, MST_PARENT_NONE, PARENT_NONE,
 };
 
 pub use nalgebra::{Const, Dyn};
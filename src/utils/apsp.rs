@@ -1,13 +1,21 @@
 use super::graph::FloatNumber;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Sentinel for no next vertex in path.
 pub const APSP_NO_PATH: usize = usize::MAX;
 
 /// APSP result buffers (V×V distance matrix + next matrix for path reconstruction).
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound = "", try_from = "super::serialization::ApspBuffersData<T>")
+)]
 pub struct ApspBuffers<T: FloatNumber> {
     pub n: usize,
-    pub dist: Vec<T>,     // Flattened V×V matrix (row-major)
+    #[cfg_attr(feature = "serde", serde(with = "super::serialization::floats"))]
+    pub dist: Vec<T>, // Flattened V×V matrix (row-major)
     pub next: Vec<usize>, // Flattened V×V matrix for path reconstruction
 }
 
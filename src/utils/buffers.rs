@@ -1,18 +1,23 @@
 use super::graph::FloatNumber;
 use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, OVector, U1};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Sentinel value for no parent in tree.
 pub const PARENT_NONE: usize = usize::MAX;
 
 /// SSSP result buffers (distances + parents).
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
 pub struct SsspBuffers<T, N>
 where
     T: FloatNumber,
     N: Dim,
     DefaultAllocator: Allocator<N>,
 {
-    pub dist: OVector<T, N>,       // Source to vertex
+    #[cfg_attr(feature = "serde", serde(with = "super::serialization::float_vector"))]
+    pub dist: OVector<T, N>, // Source to vertex
+    #[cfg_attr(feature = "serde", serde(with = "super::serialization::vector"))]
     pub parent: OVector<usize, N>, // Parent vertex
 }
 
@@ -0,0 +1,195 @@
+use super::graph::{AdjListGraph, FloatNumber, Graph};
+
+/// Compressed sparse row graph: out-edges of u are `offsets[u]..offsets[u + 1]`
+/// in `targets`/`weights`. Immutable, one allocation per array.
+#[derive(Clone, Debug)]
+pub struct CsrGraph<T: FloatNumber> {
+    offsets: Vec<usize>, // n + 1 entries
+    targets: Vec<usize>,
+    weights: Vec<T>,
+}
+
+impl<T: FloatNumber> CsrGraph<T> {
+    /// Copy of any graph, keeping each vertex's edge order.
+    pub fn from_graph<G: Graph<T>>(graph: &G) -> Self {
+        let n = graph.n();
+        let mut offsets = Vec::with_capacity(n + 1);
+        let mut targets = Vec::new();
+        let mut weights = Vec::new();
+
+        offsets.push(0);
+        for u in 0..n {
+            graph.for_each_out_edge(u, |v, w| {
+                targets.push(v);
+                weights.push(w);
+            });
+            offsets.push(targets.len());
+        }
+        Self {
+            offsets,
+            targets,
+            weights,
+        }
+    }
+
+    /// From unsorted (u, v, w) edges. Edges of one vertex keep their input order.
+    pub fn from_edges(n: usize, edges: &[(usize, usize, T)]) -> Self {
+        // Counting sort by source
+        let mut offsets = vec![0usize; n + 1];
+        for &(u, v, _) in edges {
+            debug_assert!(u < n && v < n, "Edge endpoint out of bounds");
+            offsets[u + 1] += 1;
+        }
+        for u in 0..n {
+            offsets[u + 1] += offsets[u];
+        }
+
+        let mut cursor = offsets.clone();
+        let mut targets = vec![0usize; edges.len()];
+        let mut weights = vec![T::zero(); edges.len()];
+        for &(u, v, w) in edges {
+            let slot = cursor[u];
+            targets[slot] = v;
+            weights[slot] = w;
+            cursor[u] += 1;
+        }
+        Self {
+            offsets,
+            targets,
+            weights,
+        }
+    }
+
+    /// CSR of any graph with every edge reversed; in-edges come out ordered by source.
+    pub fn reverse_of<G: Graph<T>>(graph: &G) -> Self {
+        let n = graph.n();
+        let mut offsets = vec![0usize; n + 1];
+        for u in 0..n {
+            graph.for_each_out_edge(u, |v, _| offsets[v + 1] += 1);
+        }
+        for v in 0..n {
+            offsets[v + 1] += offsets[v];
+        }
+
+        let m = offsets[n];
+        let mut cursor = offsets.clone();
+        let mut targets = vec![0usize; m];
+        let mut weights = vec![T::zero(); m];
+        for u in 0..n {
+            graph.for_each_out_edge(u, |v, w| {
+                let slot = cursor[v];
+                targets[slot] = u;
+                weights[slot] = w;
+                cursor[v] += 1;
+            });
+        }
+        Self {
+            offsets,
+            targets,
+            weights,
+        }
+    }
+
+    /// Owned copy with every edge reversed.
+    pub fn transpose(&self) -> Self {
+        Self::reverse_of(self)
+    }
+
+    /// Sort every neighbour list by target (then weight).
+    pub fn sorted(mut self) -> Self {
+        let mut row = Vec::new();
+        for u in 0..self.n() {
+            let range = self.offsets[u]..self.offsets[u + 1];
+            row.clear();
+            row.extend(
+                self.targets[range.clone()]
+                    .iter()
+                    .copied()
+                    .zip(self.weights[range.clone()].iter().copied()),
+            );
+            row.sort_unstable_by(|a, b| a.partial_cmp(b).expect("Edge weight is NaN"));
+            for (i, &(v, w)) in range.zip(row.iter()) {
+                self.targets[i] = v;
+                self.weights[i] = w;
+            }
+        }
+        self
+    }
+
+    /// Sort neighbours and keep one edge per (u, v), the cheapest.
+    pub fn deduped(self) -> Self {
+        let mut sorted = self.sorted();
+        let n = sorted.n();
+
+        let mut write = 0;
+        let mut start = 0;
+        for u in 0..n {
+            let end = sorted.offsets[u + 1];
+            for i in start..end {
+                // Sorted by (v, w): the first of a run is the minimum
+                if i > start && sorted.targets[i] == sorted.targets[i - 1] {
+                    continue;
+                }
+                sorted.targets[write] = sorted.targets[i];
+                sorted.weights[write] = sorted.weights[i];
+                write += 1;
+            }
+            start = end;
+            sorted.offsets[u + 1] = write;
+        }
+        sorted.targets.truncate(write);
+        sorted.weights.truncate(write);
+        sorted
+    }
+
+    /// Number of edges.
+    #[inline]
+    pub fn m(&self) -> usize {
+        self.targets.len()
+    }
+
+    #[inline]
+    pub fn degree(&self, u: usize) -> usize {
+        self.offsets[u + 1] - self.offsets[u]
+    }
+
+    /// Targets and weights of u's out-edges.
+    #[inline]
+    pub fn neighbors(&self, u: usize) -> (&[usize], &[T]) {
+        let range = self.offsets[u]..self.offsets[u + 1];
+        (&self.targets[range.clone()], &self.weights[range])
+    }
+
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    pub fn targets(&self) -> &[usize] {
+        &self.targets
+    }
+
+    pub fn weights(&self) -> &[T] {
+        &self.weights
+    }
+}
+
+impl<T: FloatNumber> From<&AdjListGraph<T>> for CsrGraph<T> {
+    fn from(graph: &AdjListGraph<T>) -> Self {
+        Self::from_graph(graph)
+    }
+}
+
+impl<T: FloatNumber> Graph<T> for CsrGraph<T> {
+    #[inline]
+    fn n(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    #[inline]
+    fn for_each_out_edge<F: FnMut(usize, T)>(&self, u: usize, mut f: F) {
+        let (targets, weights) = self.neighbors(u);
+        for (&v, &w) in targets.iter().zip(weights) {
+            f(v, w);
+        }
+    }
+}
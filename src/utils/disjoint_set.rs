@@ -0,0 +1,136 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Union-find over 0..n with path compression and union by rank.
+#[derive(Clone, Debug, Default)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    sets: usize,
+}
+
+impl DisjointSet {
+    /// `n` singleton sets.
+    pub fn new(n: usize) -> Self {
+        let mut set = Self::default();
+        set.reset(n);
+        set
+    }
+
+    /// Back to `n` singletons, reusing allocations.
+    pub fn reset(&mut self, n: usize) {
+        self.parent.clear();
+        self.parent.extend(0..n);
+        self.rank.clear();
+        self.rank.resize(n, 0);
+        self.sets = n;
+    }
+
+    /// Number of elements.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of disjoint sets.
+    #[inline]
+    pub fn sets(&self) -> usize {
+        self.sets
+    }
+
+    /// Representative of x's set.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // Point the whole chain at the root
+        let mut x = x;
+        while self.parent[x] != root {
+            let next = self.parent[x];
+            self.parent[x] = root;
+            x = next;
+        }
+        root
+    }
+
+    /// Merge the sets of a and b. False if already together.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (ra, rb) = (self.find(a), self.find(b));
+        if ra == rb {
+            return false;
+        }
+
+        // Attach the shallower tree below the deeper one
+        match self.rank[ra].cmp(&self.rank[rb]) {
+            std::cmp::Ordering::Less => self.parent[ra] = rb,
+            std::cmp::Ordering::Greater => self.parent[rb] = ra,
+            std::cmp::Ordering::Equal => {
+                self.parent[rb] = ra;
+                self.rank[ra] += 1;
+            }
+        }
+        self.sets -= 1;
+        true
+    }
+
+    #[inline]
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+}
+
+/// Lock-free union-find for phases where many threads merge at once.
+/// Links the larger root under the smaller, so parent chains strictly decrease.
+#[derive(Debug)]
+pub(crate) struct ConcurrentDisjointSet {
+    parent: Vec<AtomicUsize>,
+}
+
+impl ConcurrentDisjointSet {
+    pub(crate) fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).map(AtomicUsize::new).collect(),
+        }
+    }
+
+    /// Representative of x's set, halving the path on the way up.
+    pub(crate) fn find(&self, x: usize) -> usize {
+        let mut x = x;
+        loop {
+            let p = self.parent[x].load(Ordering::Acquire);
+            if p == x {
+                return x;
+            }
+            let gp = self.parent[p].load(Ordering::Acquire);
+            if gp != p {
+                // Losing the race is fine: someone else shortened it
+                let _ = self.parent[x].compare_exchange(p, gp, Ordering::AcqRel, Ordering::Acquire);
+            }
+            x = gp;
+        }
+    }
+
+    /// Merge the sets of a and b. False if already together.
+    pub(crate) fn union(&self, a: usize, b: usize) -> bool {
+        loop {
+            let (ra, rb) = (self.find(a), self.find(b));
+            if ra == rb {
+                return false;
+            }
+            let (child, root) = if ra > rb { (ra, rb) } else { (rb, ra) };
+            // Fails only if child stopped being a root; retry from the new roots
+            if self.parent[child]
+                .compare_exchange(child, root, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return true;
+            }
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use nalgebra::RealField;
 use num_traits::Float;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Generic type for weights.
 pub trait FloatNumber: Copy + RealField + Float + std::fmt::Debug + Send + Sync + 'static {}
@@ -9,8 +11,10 @@ impl<T> FloatNumber for T where T: Copy + RealField + Float + std::fmt::Debug +
 
 /// Weighted directed edge.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
 pub struct Edge<T: FloatNumber> {
     pub to: usize,
+    #[cfg_attr(feature = "serde", serde(with = "super::serialization::float"))]
     pub w: T,
 }
 
@@ -25,8 +29,18 @@ pub trait Graph<T: FloatNumber> {
     fn for_each_out_edge<F: FnMut(usize, T)>(&self, u: usize, f: F);
 }
 
+/// Graph that can also enumerate in-edges: f(u, w) for every edge u -> v.
+pub trait BidirectionalGraph<T: FloatNumber>: Graph<T> {
+    fn for_each_in_edge<F: FnMut(usize, T)>(&self, v: usize, f: F);
+}
+
 /// Adjacency list graph.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound = "", try_from = "super::serialization::AdjListGraphData<T>")
+)]
 pub struct AdjListGraph<T: FloatNumber> {
     n: usize,
     adj: Vec<Vec<Edge<T>>>,
@@ -40,6 +54,14 @@ impl<T: FloatNumber> AdjListGraph<T> {
         }
     }
 
+    /// `new`, or `None` if `n` adjacency lists can't be allocated (e.g. an untrusted header).
+    pub(crate) fn try_new(n: usize) -> Option<Self> {
+        let mut adj = Vec::new();
+        adj.try_reserve_exact(n).ok()?;
+        adj.resize(n, Vec::new());
+        Some(Self { n, adj })
+    }
+
     pub fn add_edge(&mut self, u: usize, v: usize, w: T) {
         debug_assert!(u < self.n && v < self.n);
         self.adj[u].push(Edge::new(v, w));
@@ -52,6 +74,11 @@ impl<T: FloatNumber> AdjListGraph<T> {
     pub fn m(&self) -> usize {
         self.adj.iter().map(|edges| edges.len()).sum()
     }
+
+    /// Owned copy with every edge reversed.
+    pub fn transpose(&self) -> Self {
+        transpose_of(self)
+    }
 }
 
 impl<T: FloatNumber> Graph<T> for AdjListGraph<T> {
@@ -65,3 +92,12 @@ impl<T: FloatNumber> Graph<T> for AdjListGraph<T> {
         }
     }
 }
+
+/// Owned reversed copy of any graph.
+pub(crate) fn transpose_of<T: FloatNumber, G: Graph<T>>(graph: &G) -> AdjListGraph<T> {
+    let mut reverse = AdjListGraph::new(graph.n());
+    for u in 0..graph.n() {
+        graph.for_each_out_edge(u, |v, w| reverse.add_edge(v, u, w));
+    }
+    reverse
+}
@@ -0,0 +1,174 @@
+use super::graph::{FloatNumber, Graph};
+
+/// Which neighbours a cell has.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Connectivity {
+    #[default]
+    Four,
+    Eight,
+}
+
+/// When a diagonal step past walls is allowed (8-connectivity only).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DiagonalMovement {
+    /// Ignore the two orthogonal cells.
+    Always,
+    /// At least one orthogonal cell is free (no squeezing between walls).
+    IfOneFree,
+    /// Both orthogonal cells are free (no corner cutting).
+    #[default]
+    IfBothFree,
+}
+
+/// Implicit grid: vertex r * cols + c, neighbours computed on the fly.
+/// Stepping into a cell costs its cost, times sqrt(2) on diagonals.
+/// Walls have no edges in or out.
+#[derive(Clone, Debug)]
+pub struct GridGraph<T: FloatNumber> {
+    rows: usize,
+    cols: usize,
+    walls: Vec<bool>,
+    costs: Vec<T>,
+    connectivity: Connectivity,
+    diagonal: DiagonalMovement,
+}
+
+impl<T: FloatNumber> GridGraph<T> {
+    /// Open grid, unit costs, 4-connected.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            walls: vec![false; rows * cols],
+            costs: vec![T::one(); rows * cols],
+            connectivity: Connectivity::Four,
+            diagonal: DiagonalMovement::default(),
+        }
+    }
+
+    pub fn with_connectivity(mut self, connectivity: Connectivity) -> Self {
+        self.connectivity = connectivity;
+        self
+    }
+
+    pub fn with_diagonal(mut self, diagonal: DiagonalMovement) -> Self {
+        self.diagonal = diagonal;
+        self
+    }
+
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn connectivity(&self) -> Connectivity {
+        self.connectivity
+    }
+
+    #[inline]
+    pub fn vertex(&self, row: usize, col: usize) -> usize {
+        debug_assert!(row < self.rows && col < self.cols, "Cell out of bounds");
+        row * self.cols + col
+    }
+
+    /// (row, col) of a vertex.
+    #[inline]
+    pub fn cell(&self, v: usize) -> (usize, usize) {
+        (v / self.cols, v % self.cols)
+    }
+
+    pub fn set_wall(&mut self, row: usize, col: usize, wall: bool) {
+        let v = self.vertex(row, col);
+        self.walls[v] = wall;
+    }
+
+    #[inline]
+    pub fn is_wall(&self, row: usize, col: usize) -> bool {
+        self.walls[self.vertex(row, col)]
+    }
+
+    /// Cost of stepping into (row, col). Must be positive.
+    pub fn set_cost(&mut self, row: usize, col: usize, cost: T) {
+        debug_assert!(cost > T::zero(), "Cell cost must be positive");
+        let v = self.vertex(row, col);
+        self.costs[v] = cost;
+    }
+
+    #[inline]
+    pub fn cost(&self, row: usize, col: usize) -> T {
+        self.costs[self.vertex(row, col)]
+    }
+
+    /// Cheapest open cell, so heuristics scaled by it stay admissible.
+    pub fn min_cost(&self) -> T {
+        let mut best = T::infinity();
+        for (&c, &wall) in self.costs.iter().zip(&self.walls) {
+            if !wall && c < best {
+                best = c;
+            }
+        }
+        if best.is_infinite() {
+            T::zero()
+        } else {
+            best
+        }
+    }
+
+    /// Open cell at (row + dr, col + dc), if inside the grid.
+    #[inline]
+    fn open(&self, row: usize, col: usize, dr: isize, dc: isize) -> Option<usize> {
+        let r = row.checked_add_signed(dr).filter(|&r| r < self.rows)?;
+        let c = col.checked_add_signed(dc).filter(|&c| c < self.cols)?;
+        let v = r * self.cols + c;
+        (!self.walls[v]).then_some(v)
+    }
+
+    fn diagonal_allowed(&self, row: usize, col: usize, dr: isize, dc: isize) -> bool {
+        let free_row = self.open(row, col, dr, 0).is_some();
+        let free_col = self.open(row, col, 0, dc).is_some();
+        match self.diagonal {
+            DiagonalMovement::Always => true,
+            DiagonalMovement::IfOneFree => free_row || free_col,
+            DiagonalMovement::IfBothFree => free_row && free_col,
+        }
+    }
+}
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const DIAGONAL: [(isize, isize); 4] = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
+
+impl<T: FloatNumber> Graph<T> for GridGraph<T> {
+    #[inline]
+    fn n(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    fn for_each_out_edge<F: FnMut(usize, T)>(&self, u: usize, mut f: F) {
+        if self.walls[u] {
+            return;
+        }
+        let (row, col) = self.cell(u);
+
+        for (dr, dc) in ORTHOGONAL {
+            if let Some(v) = self.open(row, col, dr, dc) {
+                f(v, self.costs[v]);
+            }
+        }
+
+        if self.connectivity == Connectivity::Eight {
+            let sqrt2 = T::from(std::f64::consts::SQRT_2).unwrap();
+            for (dr, dc) in DIAGONAL {
+                if let Some(v) = self.open(row, col, dr, dc) {
+                    if self.diagonal_allowed(row, col, dr, dc) {
+                        f(v, self.costs[v] * sqrt2);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,13 +1,27 @@
 mod apsp;
 mod buffers;
+mod csr;
+mod disjoint_set;
 mod graph;
+mod grid;
 mod mst;
 mod parallel;
 mod relaxation;
+mod reverse;
+#[cfg(feature = "serde")]
+pub(crate) mod serialization;
+mod undirected;
 
 pub use apsp::{ApspBuffers, APSP_NO_PATH};
 pub use buffers::{SsspBuffers, PARENT_NONE};
-pub use graph::{AdjListGraph, Edge, FloatNumber, Graph};
+pub use csr::CsrGraph;
+pub(crate) use disjoint_set::ConcurrentDisjointSet;
+pub use disjoint_set::DisjointSet;
+pub(crate) use graph::transpose_of;
+pub use graph::{AdjListGraph, BidirectionalGraph, Edge, FloatNumber, Graph};
+pub use grid::{Connectivity, DiagonalMovement, GridGraph};
 pub use mst::{MstBuffers, MstEdge, MST_PARENT_NONE};
 pub use parallel::{all_pairs_sssp, parallel_sssp, MultiSourceResult};
 pub use relaxation::{relax, relax_with, RelaxResult};
+pub use reverse::{ReverseGraph, WithInEdges};
+pub use undirected::{UndirectedEdge, UndirectedGraph};
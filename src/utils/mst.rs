@@ -1,5 +1,8 @@
 use super::graph::FloatNumber;
+use super::undirected::UndirectedGraph;
 use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, OVector, U1};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Sentinel value for no parent in MST.
 pub const MST_PARENT_NONE: usize = usize::MAX;
@@ -20,15 +23,19 @@ impl<T: FloatNumber> MstEdge<T> {
 
 /// MST result buffers (keys + parents + membership).
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
 pub struct MstBuffers<T, N>
 where
     T: FloatNumber,
     N: Dim,
     DefaultAllocator: Allocator<N>,
 {
-    pub key: OVector<T, N>,        // Min edge weight to include vertex
+    #[cfg_attr(feature = "serde", serde(with = "super::serialization::float_vector"))]
+    pub key: OVector<T, N>, // Min edge weight to include vertex
+    #[cfg_attr(feature = "serde", serde(with = "super::serialization::vector"))]
     pub parent: OVector<usize, N>, // Parent vertex in MST
-    pub in_mst: OVector<bool, N>,  // Whether vertex is in MST
+    #[cfg_attr(feature = "serde", serde(with = "super::serialization::vector"))]
+    pub in_mst: OVector<bool, N>, // Whether vertex is in MST
 }
 
 impl<T, N> MstBuffers<T, N>
@@ -92,6 +99,15 @@ where
         total
     }
 
+    /// Tree (or forest) edges as an undirected graph over all vertices.
+    pub fn spanning_graph(&self) -> UndirectedGraph<T> {
+        let edges = self.collect_edges();
+        UndirectedGraph::from_edges(
+            self.parent.len(),
+            edges.iter().map(|e| (e.from, e.to, e.weight)),
+        )
+    }
+
     /// Count vertices in MST.
     pub fn vertices_in_mst(&self) -> usize {
         self.in_mst.iter().filter(|&&x| x).count()
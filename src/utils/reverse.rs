@@ -0,0 +1,83 @@
+use super::csr::CsrGraph;
+use super::graph::{BidirectionalGraph, FloatNumber, Graph};
+
+/// Zero-copy view with every edge reversed: out-edges are the inner in-edges.
+#[derive(Clone, Copy, Debug)]
+pub struct ReverseGraph<'a, G> {
+    graph: &'a G,
+}
+
+impl<'a, G> ReverseGraph<'a, G> {
+    pub fn new(graph: &'a G) -> Self {
+        Self { graph }
+    }
+
+    pub fn inner(&self) -> &'a G {
+        self.graph
+    }
+}
+
+impl<T: FloatNumber, G: BidirectionalGraph<T>> Graph<T> for ReverseGraph<'_, G> {
+    #[inline]
+    fn n(&self) -> usize {
+        self.graph.n()
+    }
+
+    #[inline]
+    fn for_each_out_edge<F: FnMut(usize, T)>(&self, u: usize, f: F) {
+        self.graph.for_each_in_edge(u, f);
+    }
+}
+
+impl<T: FloatNumber, G: BidirectionalGraph<T>> BidirectionalGraph<T> for ReverseGraph<'_, G> {
+    #[inline]
+    fn for_each_in_edge<F: FnMut(usize, T)>(&self, v: usize, f: F) {
+        self.graph.for_each_out_edge(v, f);
+    }
+}
+
+/// Any graph plus a CSR index of its in-edges, built once.
+#[derive(Clone, Debug)]
+pub struct WithInEdges<T: FloatNumber, G> {
+    graph: G,
+    reverse: CsrGraph<T>,
+}
+
+impl<T: FloatNumber, G: Graph<T>> WithInEdges<T, G> {
+    pub fn new(graph: G) -> Self {
+        let reverse = CsrGraph::reverse_of(&graph);
+        Self { graph, reverse }
+    }
+
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// In-edges as a standalone graph (the transpose).
+    pub fn reverse(&self) -> &CsrGraph<T> {
+        &self.reverse
+    }
+
+    pub fn into_inner(self) -> G {
+        self.graph
+    }
+}
+
+impl<T: FloatNumber, G: Graph<T>> Graph<T> for WithInEdges<T, G> {
+    #[inline]
+    fn n(&self) -> usize {
+        self.graph.n()
+    }
+
+    #[inline]
+    fn for_each_out_edge<F: FnMut(usize, T)>(&self, u: usize, f: F) {
+        self.graph.for_each_out_edge(u, f);
+    }
+}
+
+impl<T: FloatNumber, G: Graph<T>> BidirectionalGraph<T> for WithInEdges<T, G> {
+    #[inline]
+    fn for_each_in_edge<F: FnMut(usize, T)>(&self, v: usize, f: F) {
+        self.reverse.for_each_out_edge(v, f);
+    }
+}
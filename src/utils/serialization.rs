@@ -0,0 +1,239 @@
+//! Serde helpers for float and `OVector` fields (feature `serde`).
+//!
+//! Floats go through `f64`. Human-readable formats get non-finite values as
+//! the strings `"inf"`, `"-inf"` and `"nan"`, since JSON has no infinity;
+//! binary formats get the raw `f64`.
+
+use super::apsp::ApspBuffers;
+use super::graph::{AdjListGraph, Edge, FloatNumber};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, OVector, Scalar, U1};
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// One float, JSON-safe.
+pub(crate) struct Float<T>(pub T);
+
+impl<T: FloatNumber> Serialize for Float<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let x = self.0.to_f64().unwrap_or(f64::NAN);
+        if !serializer.is_human_readable() || x.is_finite() {
+            serializer.serialize_f64(x)
+        } else if x.is_nan() {
+            serializer.serialize_str("nan")
+        } else if x > 0.0 {
+            serializer.serialize_str("inf")
+        } else {
+            serializer.serialize_str("-inf")
+        }
+    }
+}
+
+impl<'de, T: FloatNumber> Deserialize<'de> for Float<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let visitor = FloatVisitor(PhantomData);
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
+        } else {
+            deserializer.deserialize_f64(visitor)
+        }
+    }
+}
+
+struct FloatVisitor<T>(PhantomData<T>);
+
+impl<T: FloatNumber> FloatVisitor<T> {
+    fn convert<E: de::Error>(x: f64) -> Result<Float<T>, E> {
+        T::from(x)
+            .map(Float)
+            .ok_or_else(|| E::custom(format!("{x} does not fit the weight type")))
+    }
+}
+
+impl<T: FloatNumber> Visitor<'_> for FloatVisitor<T> {
+    type Value = Float<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number, \"inf\", \"-inf\" or \"nan\"")
+    }
+
+    fn visit_f64<E: de::Error>(self, x: f64) -> Result<Self::Value, E> {
+        Self::convert(x)
+    }
+
+    fn visit_i64<E: de::Error>(self, x: i64) -> Result<Self::Value, E> {
+        Self::convert(x as f64)
+    }
+
+    fn visit_u64<E: de::Error>(self, x: u64) -> Result<Self::Value, E> {
+        Self::convert(x as f64)
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        match s {
+            "inf" => Self::convert(f64::INFINITY),
+            "-inf" => Self::convert(f64::NEG_INFINITY),
+            "nan" => Self::convert(f64::NAN),
+            _ => Err(E::invalid_value(de::Unexpected::Str(s), &self)),
+        }
+    }
+}
+
+/// `#[serde(with)]` for a single `T`.
+pub(crate) mod float {
+    use super::*;
+
+    pub fn serialize<T: FloatNumber, S: Serializer>(x: &T, s: S) -> Result<S::Ok, S::Error> {
+        Float(*x).serialize(s)
+    }
+
+    pub fn deserialize<'de, T: FloatNumber, D: Deserializer<'de>>(d: D) -> Result<T, D::Error> {
+        Float::deserialize(d).map(|f| f.0)
+    }
+}
+
+/// `#[serde(with)]` for `Vec<T>`.
+pub(crate) mod floats {
+    use super::*;
+
+    pub fn serialize<T: FloatNumber, S: Serializer>(xs: &[T], s: S) -> Result<S::Ok, S::Error> {
+        s.collect_seq(xs.iter().map(|&x| Float(x)))
+    }
+
+    pub fn deserialize<'de, T: FloatNumber, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Vec<T>, D::Error> {
+        let xs = Vec::<Float<T>>::deserialize(d)?;
+        Ok(xs.into_iter().map(|f| f.0).collect())
+    }
+}
+
+/// Column vector from a sequence, checking the length against a static `N`.
+fn vector_from<N, E, Err>(items: Vec<E>) -> Result<OVector<E, N>, Err>
+where
+    N: Dim,
+    E: Scalar,
+    Err: de::Error,
+    DefaultAllocator: Allocator<N>,
+{
+    let len = items.len();
+    if let Some(expected) = N::try_to_usize() {
+        if expected != len {
+            return Err(Err::invalid_length(len, &&*format!("{expected} entries")));
+        }
+    }
+    Ok(OVector::from_vec_generic(N::from_usize(len), U1, items))
+}
+
+/// `#[serde(with)]` for `OVector<E, N>` of plain scalars (parents, flags).
+pub(crate) mod vector {
+    use super::*;
+
+    pub fn serialize<E, N, S>(v: &OVector<E, N>, s: S) -> Result<S::Ok, S::Error>
+    where
+        E: Scalar + Serialize,
+        N: Dim,
+        S: Serializer,
+        DefaultAllocator: Allocator<N>,
+    {
+        s.collect_seq(v.iter())
+    }
+
+    pub fn deserialize<'de, E, N, D>(d: D) -> Result<OVector<E, N>, D::Error>
+    where
+        E: Scalar + Deserialize<'de>,
+        N: Dim,
+        D: Deserializer<'de>,
+        DefaultAllocator: Allocator<N>,
+    {
+        vector_from(Vec::<E>::deserialize(d)?)
+    }
+}
+
+/// `#[serde(with)]` for `OVector<T, N>` of floats (distances, keys).
+pub(crate) mod float_vector {
+    use super::*;
+
+    pub fn serialize<T, N, S>(v: &OVector<T, N>, s: S) -> Result<S::Ok, S::Error>
+    where
+        T: FloatNumber,
+        N: Dim,
+        S: Serializer,
+        DefaultAllocator: Allocator<N>,
+    {
+        s.collect_seq(v.iter().map(|&x| Float(x)))
+    }
+
+    pub fn deserialize<'de, T, N, D>(d: D) -> Result<OVector<T, N>, D::Error>
+    where
+        T: FloatNumber,
+        N: Dim,
+        D: Deserializer<'de>,
+        DefaultAllocator: Allocator<N>,
+    {
+        let xs = Vec::<Float<T>>::deserialize(d)?;
+        vector_from(xs.into_iter().map(|f| f.0).collect())
+    }
+}
+
+/// Unchecked `AdjListGraph` fields, validated on conversion.
+#[derive(Deserialize)]
+#[serde(bound = "")]
+pub(crate) struct AdjListGraphData<T: FloatNumber> {
+    n: usize,
+    adj: Vec<Vec<Edge<T>>>,
+}
+
+impl<T: FloatNumber> TryFrom<AdjListGraphData<T>> for AdjListGraph<T> {
+    type Error = String;
+
+    fn try_from(data: AdjListGraphData<T>) -> Result<Self, String> {
+        if data.adj.len() != data.n {
+            return Err(format!(
+                "{} adjacency lists for n = {}",
+                data.adj.len(),
+                data.n
+            ));
+        }
+        let mut graph = AdjListGraph::new(data.n);
+        for (u, edges) in data.adj.into_iter().enumerate() {
+            for e in edges {
+                if e.to >= data.n {
+                    return Err(format!("edge {u} -> {} out of range", e.to));
+                }
+                graph.add_edge(u, e.to, e.w);
+            }
+        }
+        Ok(graph)
+    }
+}
+
+/// Unchecked `ApspBuffers` fields, validated on conversion.
+#[derive(Deserialize)]
+#[serde(bound = "")]
+pub(crate) struct ApspBuffersData<T: FloatNumber> {
+    n: usize,
+    #[serde(with = "floats")]
+    dist: Vec<T>,
+    next: Vec<usize>,
+}
+
+impl<T: FloatNumber> TryFrom<ApspBuffersData<T>> for ApspBuffers<T> {
+    type Error = String;
+
+    fn try_from(data: ApspBuffersData<T>) -> Result<Self, String> {
+        let size = data
+            .n
+            .checked_mul(data.n)
+            .ok_or_else(|| format!("n * n overflows for n = {}", data.n))?;
+        if data.dist.len() != size || data.next.len() != size {
+            return Err(format!("matrices must have n * n = {size} entries"));
+        }
+        Ok(Self {
+            n: data.n,
+            dist: data.dist,
+            next: data.next,
+        })
+    }
+}
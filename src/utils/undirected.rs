@@ -0,0 +1,113 @@
+use super::graph::{BidirectionalGraph, FloatNumber, Graph};
+
+/// Undirected edge u -- v, stored once.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UndirectedEdge<T: FloatNumber> {
+    pub u: usize,
+    pub v: usize,
+    pub w: T,
+}
+
+impl<T: FloatNumber> UndirectedEdge<T> {
+    /// Endpoint opposite `x`.
+    #[inline]
+    pub fn other(&self, x: usize) -> usize {
+        if x == self.u {
+            self.v
+        } else {
+            self.u
+        }
+    }
+}
+
+/// Undirected graph: one record per edge, ids are insertion order and never change.
+/// Each edge is visited from both endpoints, with the same weight.
+#[derive(Clone, Debug)]
+pub struct UndirectedGraph<T: FloatNumber> {
+    edges: Vec<UndirectedEdge<T>>,
+    incident: Vec<Vec<usize>>, // Edge ids per vertex (self-loops once)
+}
+
+impl<T: FloatNumber> UndirectedGraph<T> {
+    pub fn new(n: usize) -> Self {
+        Self {
+            edges: Vec::new(),
+            incident: vec![Vec::new(); n],
+        }
+    }
+
+    pub fn from_edges<I: IntoIterator<Item = (usize, usize, T)>>(n: usize, edges: I) -> Self {
+        let mut graph = Self::new(n);
+        for (u, v, w) in edges {
+            graph.add_edge(u, v, w);
+        }
+        graph
+    }
+
+    /// Add u -- v and return its id.
+    pub fn add_edge(&mut self, u: usize, v: usize, w: T) -> usize {
+        let n = self.incident.len();
+        debug_assert!(u < n && v < n);
+
+        let id = self.edges.len();
+        self.edges.push(UndirectedEdge { u, v, w });
+        self.incident[u].push(id);
+        if u != v {
+            self.incident[v].push(id);
+        }
+        id
+    }
+
+    #[inline]
+    pub fn edge(&self, id: usize) -> &UndirectedEdge<T> {
+        &self.edges[id]
+    }
+
+    /// Reweight both directions at once.
+    pub fn set_weight(&mut self, id: usize, w: T) {
+        self.edges[id].w = w;
+    }
+
+    pub fn edges(&self) -> &[UndirectedEdge<T>] {
+        &self.edges
+    }
+
+    /// Ids of edges touching u.
+    #[inline]
+    pub fn incident(&self, u: usize) -> &[usize] {
+        &self.incident[u]
+    }
+
+    #[inline]
+    pub fn degree(&self, u: usize) -> usize {
+        self.incident[u].len()
+    }
+
+    /// Number of undirected edges.
+    pub fn m(&self) -> usize {
+        self.edges.len()
+    }
+}
+
+impl<T: FloatNumber> Graph<T> for UndirectedGraph<T> {
+    #[inline]
+    fn n(&self) -> usize {
+        self.incident.len()
+    }
+
+    #[inline]
+    fn for_each_out_edge<F: FnMut(usize, T)>(&self, u: usize, mut f: F) {
+        for &id in &self.incident[u] {
+            let e = &self.edges[id];
+            f(e.other(u), e.w);
+        }
+    }
+}
+
+/// Symmetric: in-edges are the out-edges.
+impl<T: FloatNumber> BidirectionalGraph<T> for UndirectedGraph<T> {
+    #[inline]
+    fn for_each_in_edge<F: FnMut(usize, T)>(&self, v: usize, f: F) {
+        self.for_each_out_edge(v, f);
+    }
+}
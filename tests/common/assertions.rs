@@ -1,5 +1,5 @@
 use nalgebra::{allocator::Allocator, DefaultAllocator, Dim};
-use sssp_fast::{ApspBuffers, FloatNumber, SsspBuffers};
+use sssp_fast::{ApspBuffers, FloatNumber, Graph, NegativeCycle, SsspBuffers};
 
 pub const EPS_F64: f64 = 1e-10;
 pub const EPS_F32: f32 = 1e-6;
@@ -89,6 +89,44 @@ where
     );
 }
 
+/// Cheapest-edge weight of a vertex path, None if an edge is missing.
+pub fn path_weight<T: FloatNumber, G: Graph<T>>(graph: &G, path: &[usize]) -> Option<T> {
+    let mut total = T::zero();
+    for pair in path.windows(2) {
+        let mut best = T::infinity();
+        graph.for_each_out_edge(pair[0], |to, w| {
+            if to == pair[1] && w < best {
+                best = w;
+            }
+        });
+        if best.is_infinite() {
+            return None;
+        }
+        total += best;
+    }
+    Some(total)
+}
+
+/// `path_to(target)` uses real edges and costs `dist[target]`.
+pub fn path_valid<T, N, G>(
+    graph: &G,
+    buffers: &SsspBuffers<T, N>,
+    source: usize,
+    target: usize,
+    eps: T,
+) where
+    T: FloatNumber,
+    N: Dim,
+    G: Graph<T>,
+    DefaultAllocator: Allocator<N>,
+{
+    let path = buffers.path_to(target).expect("Target unreachable");
+    assert_eq!(path.first(), Some(&source), "Path does not start at source");
+    assert_eq!(path.last(), Some(&target), "Path does not end at target");
+    let weight = path_weight(graph, &path).expect("Path uses a missing edge");
+    approx_eq(weight, buffers.dist[target], eps);
+}
+
 // APSP-specific
 
 pub fn apsp_dist_eq<T: FloatNumber>(
@@ -126,3 +164,38 @@ pub fn apsp_path_eq<T: FloatNumber>(
         j
     );
 }
+
+// Negative cycles
+
+/// Cycle is simple, uses real edges, and has the reported negative weight.
+pub fn negative_cycle_valid<T, G>(graph: &G, cycle: &NegativeCycle<T>, eps: T)
+where
+    T: FloatNumber,
+    G: Graph<T>,
+{
+    assert!(!cycle.vertices.is_empty(), "Empty cycle");
+
+    let mut seen = cycle.vertices.clone();
+    seen.sort_unstable();
+    seen.dedup();
+    assert_eq!(seen.len(), cycle.vertices.len(), "Cycle repeats a vertex");
+
+    let mut total = T::zero();
+    for (u, v) in cycle.edges() {
+        let mut best = T::infinity();
+        graph.for_each_out_edge(u, |to, w| {
+            if to == v && w < best {
+                best = w;
+            }
+        });
+        assert!(!best.is_infinite(), "Missing cycle edge ({}, {})", u, v);
+        total += best;
+    }
+
+    approx_eq(cycle.weight, total, eps);
+    assert!(
+        cycle.weight < T::zero(),
+        "Cycle weight {:?} not negative",
+        cycle.weight
+    );
+}
@@ -1,4 +1,4 @@
-use sssp_fast::{AdjListGraph, FloatNumber};
+use sssp_fast::{AdjListGraph, FloatNumber, Graph};
 
 /// Simple linear chain: 0 -> 1 -> 2 -> ... -> n-1
 pub fn linear<T: FloatNumber>(n: usize, weight: T) -> AdjListGraph<T> {
@@ -144,3 +144,72 @@ pub fn disconnected_undirected<T: FloatNumber>(n: usize, weight: T) -> AdjListGr
     }
     g
 }
+
+/// Random directed graph with m edges and integer weights in [0, max_w].
+/// Deterministic for a given seed (xorshift).
+pub fn random<T: FloatNumber>(n: usize, m: usize, max_w: u64, seed: u64) -> AdjListGraph<T> {
+    let mut g = AdjListGraph::new(n);
+    let mut state = seed.max(1);
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    for _ in 0..m {
+        let u = (next() % n as u64) as usize;
+        let v = (next() % n as u64) as usize;
+        let w = T::from(next() % (max_w + 1)).unwrap();
+        g.add_edge(u, v, w);
+    }
+    g
+}
+
+/// Random graph shifted by `shift` per edge, so negative cycles are likely.
+pub fn random_shifted<T: FloatNumber>(
+    n: usize,
+    m: usize,
+    max_w: u64,
+    shift: T,
+    seed: u64,
+) -> AdjListGraph<T> {
+    let r = random::<T>(n, m, max_w, seed);
+    let mut g = AdjListGraph::new(n);
+    for u in 0..n {
+        r.for_each_out_edge(u, |v, w| g.add_edge(u, v, w - shift));
+    }
+    g
+}
+
+/// Random undirected graph: each of the m random edges added both ways.
+pub fn random_undirected<T: FloatNumber>(
+    n: usize,
+    m: usize,
+    max_w: u64,
+    seed: u64,
+) -> AdjListGraph<T> {
+    let r = random::<T>(n, m, max_w, seed);
+    let mut g = AdjListGraph::new(n);
+    for u in 0..n {
+        r.for_each_out_edge(u, |v, w| add_undirected_edge(&mut g, u, v, w));
+    }
+    g
+}
+
+/// Out-edges of `u` as (target, weight), in the graph's iteration order.
+pub fn out_edges<T: FloatNumber, G: Graph<T>>(g: &G, u: usize) -> Vec<(usize, T)> {
+    let mut out = Vec::new();
+    g.for_each_out_edge(u, |v, w| out.push((v, w)));
+    out
+}
+
+/// Classic 4-vertex undirected MST example: MST = {1-3, 1-2, 0-1}, weight 7.
+pub fn classic_mst_graph() -> AdjListGraph<f64> {
+    let mut g = AdjListGraph::new(4);
+    add_undirected_edge(&mut g, 0, 1, 4.0);
+    add_undirected_edge(&mut g, 0, 3, 5.0);
+    add_undirected_edge(&mut g, 1, 2, 2.0);
+    add_undirected_edge(&mut g, 1, 3, 1.0);
+    add_undirected_edge(&mut g, 2, 3, 3.0);
+    g
+}
@@ -78,3 +78,26 @@ fn test_source_equals_target() {
 
     dist_eq(&buf, 2, 0.0, EPS);
 }
+
+#[test]
+fn test_with_indexed_heap() {
+    use sssp_fast::{AStar, IndexedBinaryHeap, SsspAlgorithm};
+
+    let g = grid(10, 10, 1.0);
+    let mut buf = dynamic(100);
+    let mut astar =
+        AStar::<f64, _, IndexedBinaryHeap<f64>>::new(99, ManhattanHeuristic { cols: 10 });
+    astar.run(&g, 0, &mut buf);
+    dist_eq(&buf, 99, 18.0, EPS);
+}
+
+#[test]
+fn test_with_dary_heap() {
+    use sssp_fast::{AStar, DaryHeap, SsspAlgorithm};
+
+    let g = grid(10, 10, 1.0);
+    let mut buf = dynamic(100);
+    let mut astar = AStar::<f64, _, DaryHeap<f64, 4>>::new(99, ManhattanHeuristic { cols: 10 });
+    astar.run(&g, 0, &mut buf);
+    dist_eq(&buf, 99, 18.0, EPS);
+}
@@ -102,3 +102,62 @@ fn test_self_loop_negative() {
 
     assert!(result.negative_cycle);
 }
+
+#[test]
+fn test_negative_cycle_extracted() {
+    // Tail 0 -> 1 leading into cycle 1 -> 2 -> 3 -> 1 (weight -1)
+    let mut g: AdjListGraph<f64> = AdjListGraph::new(4);
+    g.add_edge(0, 1, 1.0);
+    g.add_edge(1, 2, 2.0);
+    g.add_edge(2, 3, 2.0);
+    g.add_edge(3, 1, -5.0);
+
+    let mut buf = dynamic(4);
+    let result = cheeky_bellman_ford(&g, 0, &mut buf);
+
+    let cycle = result.cycle.expect("cycle not extracted");
+    negative_cycle_valid(&g, &cycle, EPS);
+    approx_eq(cycle.weight, -1.0, EPS);
+
+    let mut vertices = cycle.vertices.clone();
+    vertices.sort_unstable();
+    assert_eq!(vertices, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_self_loop_cycle_extracted() {
+    let mut g: AdjListGraph<f64> = AdjListGraph::new(2);
+    g.add_edge(0, 1, 1.0);
+    g.add_edge(1, 1, -1.0);
+
+    let mut buf = dynamic(2);
+    let result = cheeky_bellman_ford(&g, 0, &mut buf);
+
+    let cycle = result.cycle.expect("cycle not extracted");
+    assert_eq!(cycle.vertices, vec![1]);
+    approx_eq(cycle.weight, -1.0, EPS);
+}
+
+#[test]
+fn test_no_cycle_extracted() {
+    let g = linear(5, -1.0);
+    let mut buf = dynamic(5);
+    let result = cheeky_bellman_ford(&g, 0, &mut buf);
+
+    assert!(!result.negative_cycle);
+    assert!(result.cycle.is_none());
+}
+
+#[test]
+fn test_random_negative_cycles() {
+    for seed in 1..30 {
+        let g = random_shifted::<f64>(40, 120, 10, 3.0, seed);
+        let mut buf = dynamic(40);
+        let result = cheeky_bellman_ford(&g, 0, &mut buf);
+
+        assert_eq!(result.negative_cycle, result.cycle.is_some());
+        if let Some(cycle) = &result.cycle {
+            negative_cycle_valid(&g, cycle, EPS);
+        }
+    }
+}
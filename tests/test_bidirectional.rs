@@ -0,0 +1,138 @@
+mod common;
+
+use common::{assertions::EPS_F64 as EPS, *};
+use sssp_fast::{cheeky_bidirectional_dijkstra, AdjListGraph, BidirectionalDijkstra, Graph};
+
+/// Distance and path to target match plain Dijkstra.
+fn matches_dijkstra(g: &AdjListGraph<f64>, source: usize, target: usize) {
+    let mut expected = dynamic(g.n());
+    cheeky_dijkstra(g, source, &mut expected);
+
+    let mut buf = dynamic(g.n());
+    cheeky_bidirectional_dijkstra(g, source, target, &mut buf);
+
+    if expected.dist[target].is_infinite() {
+        unreachable(&buf, target);
+        return;
+    }
+    dist_eq(&buf, target, expected.dist[target], EPS);
+    path_valid(g, &buf, source, target, EPS);
+}
+
+#[test]
+fn test_linear_chain() {
+    let g = linear(5, 1.0);
+    let mut buf = dynamic(5);
+    cheeky_bidirectional_dijkstra(&g, 0, 4, &mut buf);
+
+    dist_eq(&buf, 4, 4.0, EPS);
+    path_eq(&buf, 4, &[0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_diamond_selects_shortest() {
+    let (g, optimal) = diamond(1.0, 1.0, 3.0, 10.0, 1.0);
+    let mut buf = dynamic(4);
+    cheeky_bidirectional_dijkstra(&g, 0, 3, &mut buf);
+
+    dist_eq(&buf, 3, optimal, EPS);
+    path_valid(&g, &buf, 0, 3, EPS);
+}
+
+#[test]
+fn test_disconnected_graph() {
+    let g = disconnected(6, 1.0);
+    let mut buf = dynamic(6);
+    cheeky_bidirectional_dijkstra(&g, 0, 4, &mut buf);
+
+    unreachable(&buf, 4);
+    no_path(&buf, 4);
+}
+
+#[test]
+fn test_source_is_target() {
+    let g = grid(3, 3, 1.0);
+    let mut buf = dynamic(9);
+    cheeky_bidirectional_dijkstra(&g, 4, 4, &mut buf);
+
+    dist_eq(&buf, 4, 0.0, EPS);
+    path_eq(&buf, 4, &[4]);
+}
+
+#[test]
+fn test_grid_all_targets() {
+    let g = grid(6, 6, 1.0);
+    for target in 0..g.n() {
+        matches_dijkstra(&g, 0, target);
+    }
+}
+
+#[test]
+fn test_random_graphs() {
+    for seed in 1..20 {
+        // max_w = 3 gives plenty of ties and zero-weight edges
+        let g = random::<f64>(60, 240, 3, seed);
+        for target in [1, 17, 42, 59] {
+            matches_dijkstra(&g, 0, target);
+        }
+    }
+}
+
+#[test]
+fn test_zero_weight_cycle() {
+    let mut g: AdjListGraph<f64> = AdjListGraph::new(4);
+    g.add_edge(0, 1, 0.0);
+    g.add_edge(1, 2, 0.0);
+    g.add_edge(2, 1, 0.0);
+    g.add_edge(2, 3, 0.0);
+
+    let mut buf = dynamic(4);
+    cheeky_bidirectional_dijkstra(&g, 0, 3, &mut buf);
+
+    dist_eq(&buf, 3, 0.0, EPS);
+    path_valid(&g, &buf, 0, 3, EPS);
+}
+
+#[test]
+fn test_iteration_counts() {
+    let g = linear(10, 1.0);
+    let mut rev: AdjListGraph<f64> = AdjListGraph::new(10);
+    for u in 0..9 {
+        rev.add_edge(u + 1, u, 1.0);
+    }
+
+    let mut buf = dynamic(10);
+    let result = BidirectionalDijkstra::<f64>::new(9).run_with_reverse(&g, &rev, 0, &mut buf);
+
+    assert!(result.forward_iterations > 0);
+    assert!(result.backward_iterations > 0);
+    assert_eq!(
+        result.sssp.iterations,
+        result.forward_iterations + result.backward_iterations
+    );
+    path_eq(&buf, 9, &(0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_explores_less_than_dijkstra() {
+    let g = grid(30, 30, 1.0);
+    let target = 15 * 30 + 15;
+
+    let mut buf = dynamic(g.n());
+    let result = cheeky_bidirectional_dijkstra(&g, 0, target, &mut buf);
+
+    let mut dij = dynamic(g.n());
+    let full = sssp_fast::dijkstra_to(&g, 0, target, &mut dij);
+
+    dist_eq(&buf, target, 30.0, EPS);
+    assert!(result.iterations < full.iterations);
+}
+
+#[test]
+fn test_algo_info() {
+    check_algo_info(
+        &BidirectionalDijkstra::<f64>::new(0),
+        "Bidirectional Dijkstra",
+        false,
+    );
+}
@@ -0,0 +1,143 @@
+mod common;
+
+use common::assertions::EPS_F64 as EPS;
+use common::*;
+use sssp_fast::{
+    cheeky_boruvka, cheeky_kruskal, cheeky_prim, AdjListGraph, Boruvka, BoruvkaConfig, Const,
+    MstAlgorithm, MstBuffers,
+};
+
+#[test]
+fn test_classic_mst() {
+    let g = classic_mst_graph();
+    let mut buf = mst_dynamic(4);
+    let result = cheeky_boruvka(&g, 0, &mut buf);
+
+    approx_eq(result.total_weight, 7.0, EPS);
+    assert!(result.is_connected);
+    assert_eq!(buf.collect_edges().len(), 3);
+}
+
+#[test]
+fn test_disconnected_forest() {
+    let g = disconnected_undirected(6, 1.0);
+    let mut buf = mst_dynamic(6);
+    let result = cheeky_boruvka(&g, 4, &mut buf);
+
+    assert_eq!(result.vertices_in_mst, 6);
+    approx_eq(result.total_weight, 4.0, EPS);
+    assert!(!result.is_connected);
+    assert_eq!(buf.parent_of(4), None);
+    assert_eq!(buf.parent_of(0), None);
+}
+
+#[test]
+fn test_isolated_vertices() {
+    let g: AdjListGraph<f64> = AdjListGraph::new(3);
+    let mut buf = mst_dynamic(3);
+    let result = cheeky_boruvka(&g, 0, &mut buf);
+
+    assert_eq!(result.vertices_in_mst, 3);
+    assert_eq!(result.iterations, 0);
+    assert!(!result.is_connected);
+}
+
+#[test]
+fn test_parallel_edges_and_self_loops() {
+    let mut g: AdjListGraph<f64> = AdjListGraph::new(2);
+    add_undirected_edge(&mut g, 0, 1, 5.0);
+    add_undirected_edge(&mut g, 0, 1, 3.0);
+    g.add_edge(1, 1, 0.5);
+
+    let mut buf = mst_dynamic(2);
+    let result = cheeky_boruvka(&g, 0, &mut buf);
+
+    approx_eq(result.total_weight, 3.0, EPS);
+    assert!(result.is_connected);
+}
+
+#[test]
+fn test_logarithmic_rounds() {
+    // Components at least halve every round
+    let g = grid_undirected(16, 16, 1.0);
+    let mut buf = mst_dynamic(256);
+    let result = cheeky_boruvka(&g, 0, &mut buf);
+
+    approx_eq(result.total_weight, 255.0, EPS);
+    assert!(result.is_connected);
+    assert!(result.iterations <= 8);
+}
+
+#[test]
+fn test_ties_match_kruskal() {
+    // All-equal weights: the tie order decides the tree, and both use (w, lo, hi)
+    let g = grid_undirected(12, 12, 1.0);
+    let mut bb = mst_dynamic(144);
+    let mut kb = mst_dynamic(144);
+    cheeky_boruvka(&g, 0, &mut bb);
+    cheeky_kruskal(&g, 0, &mut kb);
+
+    assert_eq!(bb.parent, kb.parent);
+}
+
+#[test]
+fn test_matches_kruskal_and_prim() {
+    for seed in 1..20 {
+        let g = random_undirected::<f64>(60, 200, 10, seed);
+
+        let mut bb = mst_dynamic(60);
+        let b = cheeky_boruvka(&g, 3, &mut bb);
+        let mut kb = mst_dynamic(60);
+        let k = cheeky_kruskal(&g, 3, &mut kb);
+
+        approx_eq(b.total_weight, k.total_weight, EPS);
+        assert_eq!(b.is_connected, k.is_connected);
+        assert_eq!(bb.parent, kb.parent);
+
+        if b.is_connected {
+            let mut pb = mst_dynamic(60);
+            approx_eq(
+                cheeky_prim(&g, 3, &mut pb).total_weight,
+                b.total_weight,
+                EPS,
+            );
+        }
+    }
+}
+
+#[test]
+fn test_deterministic_across_threads() {
+    let g = random_undirected::<f64>(2_000, 10_000, 5, 42);
+
+    let mut reference = mst_dynamic(2_000);
+    Boruvka::with_config(BoruvkaConfig::new().sequential()).run(&g, 0, &mut reference);
+
+    for _ in 0..5 {
+        let mut buf = mst_dynamic(2_000);
+        cheeky_boruvka(&g, 0, &mut buf);
+        assert_eq!(buf.parent, reference.parent);
+        assert_eq!(buf.key, reference.key);
+    }
+}
+
+#[test]
+fn test_reuse() {
+    let mut boruvka = Boruvka::<f64>::new();
+
+    let g = grid_undirected(3, 3, 2.0);
+    let mut buf = mst_dynamic(9);
+    approx_eq(boruvka.run(&g, 0, &mut buf).total_weight, 16.0, EPS);
+
+    let g = classic_mst_graph();
+    let mut buf = mst_dynamic(4);
+    approx_eq(boruvka.run(&g, 1, &mut buf).total_weight, 7.0, EPS);
+}
+
+#[test]
+fn test_static_dims() {
+    let g = classic_mst_graph();
+    let mut buf: MstBuffers<f64, Const<4>> = MstBuffers::new_inf(Const::<4>);
+    let result = cheeky_boruvka(&g, 0, &mut buf);
+
+    approx_eq(result.total_weight, 7.0, EPS);
+}
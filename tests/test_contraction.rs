@@ -0,0 +1,131 @@
+mod common;
+
+use common::{assertions::EPS_F64 as EPS, *};
+use sssp_fast::{AdjListGraph, ChQuery, ContractionConfig, ContractionHierarchy, Graph};
+
+/// Every pair agrees with Dijkstra, and unpacked paths use real edges.
+fn matches_dijkstra(g: &AdjListGraph<f64>, ch: &ContractionHierarchy<f64>) {
+    let mut engine = ch.query_engine();
+    for s in 0..g.n() {
+        let mut expected = dynamic(g.n());
+        cheeky_dijkstra(g, s, &mut expected);
+
+        for t in 0..g.n() {
+            let result = engine.query(s, t);
+            if expected.dist[t].is_infinite() {
+                assert!(result.is_none(), "Expected {} -> {} unreachable", s, t);
+                continue;
+            }
+
+            let found = result.expect("Path not found");
+            approx_eq(found.distance, expected.dist[t], EPS);
+            assert_eq!(found.path.first(), Some(&s));
+            assert_eq!(found.path.last(), Some(&t));
+            let weight = path_weight(g, &found.path).expect("Path uses a missing edge");
+            approx_eq(weight, found.distance, EPS);
+        }
+    }
+}
+
+#[test]
+fn test_linear_chain() {
+    let g = linear(6, 1.0);
+    let ch = ContractionHierarchy::new(&g);
+
+    let found = ch.query(0, 5).unwrap();
+    approx_eq(found.distance, 5.0, EPS);
+    assert_eq!(found.path, vec![0, 1, 2, 3, 4, 5]);
+    assert!(ch.query(5, 0).is_none());
+}
+
+#[test]
+fn test_source_is_target() {
+    let g = grid(3, 3, 1.0);
+    let ch = ContractionHierarchy::new(&g);
+
+    let found = ch.query(4, 4).unwrap();
+    approx_eq(found.distance, 0.0, EPS);
+    assert_eq!(found.path, vec![4]);
+}
+
+#[test]
+fn test_diamond_selects_shortest() {
+    let (g, optimal) = diamond(1.0, 1.0, 3.0, 10.0, 1.0);
+    let ch = ContractionHierarchy::new(&g);
+
+    approx_eq(ch.query(0, 3).unwrap().distance, optimal, EPS);
+    matches_dijkstra(&g, &ch);
+}
+
+#[test]
+fn test_grid_undirected() {
+    let g = grid_undirected(6, 6, 1.0);
+    let ch = ContractionHierarchy::new(&g);
+
+    assert!(ch.num_shortcuts() > 0);
+    matches_dijkstra(&g, &ch);
+}
+
+#[test]
+fn test_disconnected_graph() {
+    let g = disconnected_undirected(8, 1.5);
+    let ch = ContractionHierarchy::new(&g);
+    matches_dijkstra(&g, &ch);
+}
+
+#[test]
+fn test_random_graphs() {
+    for seed in 1..8 {
+        // Small max weight: many ties and zero-weight edges
+        let g = random::<f64>(40, 160, 3, seed);
+        let ch = ContractionHierarchy::new(&g);
+        matches_dijkstra(&g, &ch);
+    }
+}
+
+#[test]
+fn test_tiny_witness_limit() {
+    let g = random::<f64>(40, 200, 10, 7);
+    let config = ContractionConfig::default().with_witness_limit(1);
+    let ch = ContractionHierarchy::with_config(&g, &config);
+
+    // More shortcuts, same answers
+    assert!(ch.num_shortcuts() >= ContractionHierarchy::new(&g).num_shortcuts());
+    matches_dijkstra(&g, &ch);
+}
+
+#[test]
+fn test_parallel_edges_and_self_loops() {
+    let mut g: AdjListGraph<f64> = AdjListGraph::new(3);
+    g.add_edge(0, 1, 5.0);
+    g.add_edge(0, 1, 2.0);
+    g.add_edge(1, 1, 0.0);
+    g.add_edge(1, 2, 1.0);
+
+    let ch = ContractionHierarchy::new(&g);
+    let found = ch.query(0, 2).unwrap();
+    approx_eq(found.distance, 3.0, EPS);
+    assert_eq!(found.path, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_rank_is_permutation() {
+    let g = grid(4, 4, 1.0);
+    let ch = ContractionHierarchy::new(&g);
+
+    let mut ranks: Vec<usize> = (0..g.n()).map(|v| ch.rank(v)).collect();
+    ranks.sort_unstable();
+    assert_eq!(ranks, (0..g.n()).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_engine_reuse_with_custom_heap() {
+    let g = grid_undirected(5, 5, 2.0);
+    let ch = ContractionHierarchy::new(&g);
+    let mut engine: ChQuery<'_, f64, sssp_fast::PairingHeap<f64>> = ChQuery::new(&ch);
+
+    for _ in 0..3 {
+        approx_eq(engine.query(0, 24).unwrap().distance, 16.0, EPS);
+        approx_eq(engine.query(24, 0).unwrap().distance, 16.0, EPS);
+    }
+}
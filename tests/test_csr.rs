@@ -0,0 +1,120 @@
+mod common;
+
+use common::assertions::EPS_F64 as EPS;
+use common::*;
+use sssp_fast::{
+    cheeky_bellman_ford, cheeky_dijkstra, cheeky_floyd_warshall, cheeky_kruskal, cheeky_prim,
+    ApspBuffers, CsrGraph, Graph,
+};
+
+#[test]
+fn test_from_adj_list_keeps_order() {
+    let g = random::<f64>(30, 120, 10, 3);
+    let csr = CsrGraph::from(&g);
+
+    assert_eq!(csr.n(), g.n());
+    assert_eq!(csr.m(), g.m());
+    for u in 0..g.n() {
+        assert_eq!(out_edges(&csr, u), out_edges(&g, u));
+        assert_eq!(csr.degree(u), g.neighbors(u).len());
+    }
+}
+
+#[test]
+fn test_from_unsorted_edges() {
+    let edges = [(2, 0, 1.0), (0, 2, 4.0), (1, 2, 2.0), (0, 1, 3.0)];
+    let csr = CsrGraph::from_edges(3, &edges);
+
+    assert_eq!(csr.offsets(), &[0, 2, 3, 4]);
+    let (targets, weights) = csr.neighbors(0);
+    assert_eq!(targets, &[2, 1]);
+    assert_eq!(weights, &[4.0, 3.0]);
+    assert_eq!(csr.neighbors(1), (&[2usize][..], &[2.0][..]));
+}
+
+#[test]
+fn test_sorted_neighbors() {
+    let edges = [(0, 3, 1.0), (0, 1, 5.0), (0, 2, 2.0), (0, 1, 4.0)];
+    let csr = CsrGraph::from_edges(4, &edges).sorted();
+
+    let (targets, weights) = csr.neighbors(0);
+    assert_eq!(targets, &[1, 1, 2, 3]);
+    assert_eq!(weights, &[4.0, 5.0, 2.0, 1.0]);
+}
+
+#[test]
+fn test_dedup_keeps_min_weight() {
+    let edges = [
+        (0, 1, 5.0),
+        (1, 0, 2.0),
+        (0, 1, 3.0),
+        (0, 2, 9.0),
+        (0, 1, 7.0),
+        (1, 0, 1.0),
+    ];
+    let csr = CsrGraph::from_edges(3, &edges).deduped();
+
+    assert_eq!(csr.m(), 3);
+    assert_eq!(csr.offsets(), &[0, 2, 3, 3]);
+    assert_eq!(out_edges(&csr, 0), vec![(1, 3.0), (2, 9.0)]);
+    assert_eq!(out_edges(&csr, 1), vec![(0, 1.0)]);
+    assert!(out_edges(&csr, 2).is_empty());
+}
+
+#[test]
+fn test_empty_and_isolated() {
+    let csr = CsrGraph::<f64>::from_edges(0, &[]);
+    assert_eq!(csr.n(), 0);
+    assert_eq!(csr.m(), 0);
+
+    let csr = CsrGraph::<f64>::from_edges(4, &[(3, 0, 1.0)]).deduped();
+    assert_eq!(csr.n(), 4);
+    assert_eq!(csr.degree(0), 0);
+    assert_eq!(csr.degree(3), 1);
+}
+
+#[test]
+fn test_sssp_matches_adj_list() {
+    for seed in 1..10 {
+        let g = random::<f64>(50, 250, 20, seed);
+        let csr = CsrGraph::from(&g);
+
+        let mut a = dynamic(50);
+        let mut b = dynamic(50);
+        cheeky_dijkstra(&g, 0, &mut a);
+        cheeky_dijkstra(&csr, 0, &mut b);
+        assert_eq!(a.dist, b.dist);
+        assert_eq!(a.parent, b.parent);
+
+        let mut c = dynamic(50);
+        cheeky_bellman_ford(&csr, 0, &mut c);
+        assert_eq!(c.dist, a.dist);
+    }
+}
+
+#[test]
+fn test_dedup_preserves_distances() {
+    let g = random::<f64>(40, 400, 15, 11);
+    let csr = CsrGraph::from(&g).deduped();
+    assert!(csr.m() < g.m());
+
+    let mut a = ApspBuffers::new(40);
+    let mut b = ApspBuffers::new(40);
+    cheeky_floyd_warshall(&g, &mut a);
+    cheeky_floyd_warshall(&csr, &mut b);
+    assert_eq!(a.dist, b.dist);
+}
+
+#[test]
+fn test_mst_matches_adj_list() {
+    let g = grid_undirected(6, 6, 1.0);
+    let csr = CsrGraph::from(&g);
+
+    let mut a = mst_dynamic(36);
+    let mut b = mst_dynamic(36);
+    approx_eq(
+        cheeky_prim(&csr, 0, &mut a).total_weight,
+        cheeky_kruskal(&g, 0, &mut b).total_weight,
+        EPS,
+    );
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use common::{assertions::EPS_F64 as EPS, *};
+use sssp_fast::{
+    cheeky_delta_stepping, delta_stepping_to, AdjListGraph, DeltaStepping, DeltaSteppingConfig,
+    Graph, SsspAlgorithm,
+};
+
+/// Check distances match Dijkstra for every vertex.
+fn matches_dijkstra(g: &AdjListGraph<f64>, source: usize, config: DeltaSteppingConfig) {
+    let n = g.n();
+
+    let mut expected = dynamic(n);
+    cheeky_dijkstra(g, source, &mut expected);
+
+    let mut buf = dynamic(n);
+    DeltaStepping::<f64>::with_config(config).run(g, source, &mut buf);
+
+    for v in 0..n {
+        if expected.dist[v].is_infinite() {
+            unreachable(&buf, v);
+        } else {
+            dist_eq(&buf, v, expected.dist[v], EPS);
+            assert_eq!(buf.path_to(v).unwrap()[0], source);
+        }
+    }
+}
+
+fn configs() -> Vec<DeltaSteppingConfig> {
+    vec![
+        DeltaSteppingConfig::default(),
+        DeltaSteppingConfig::default().with_delta(0.5),
+        DeltaSteppingConfig::default().with_delta(3.0),
+        DeltaSteppingConfig::default().with_delta(1000.0),
+    ]
+}
+
+#[test]
+fn test_linear_chain() {
+    let g = linear(5, 1.0);
+    let mut buf = dynamic(5);
+    cheeky_delta_stepping(&g, 0, &mut buf);
+
+    for i in 0..5 {
+        dist_eq(&buf, i, i as f64, EPS);
+    }
+    path_eq(&buf, 4, &[0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_matches_dijkstra_on_common_graphs() {
+    let (diamond, _) = diamond(1.0, 1.0, 3.0, 10.0, 1.0);
+    let graphs = [
+        linear(10, 1.0),
+        complete(20, 2.0),
+        diamond,
+        grid(8, 8, 1.0),
+        disconnected(10, 1.0),
+        star(10, 3.0),
+        linear_undirected(10, 0.5),
+        grid_undirected(6, 6, 2.0),
+        disconnected_undirected(10, 1.0),
+    ];
+
+    for g in &graphs {
+        for config in configs() {
+            matches_dijkstra(g, 0, config);
+        }
+    }
+}
+
+#[test]
+fn test_random_graphs() {
+    for seed in 1..10 {
+        let g = random::<f64>(300, 1500, 50, seed);
+        for config in configs() {
+            matches_dijkstra(&g, 0, config);
+        }
+    }
+}
+
+#[test]
+fn test_zero_weights() {
+    let g = linear(5, 0.0);
+    let mut buf = dynamic(5);
+    cheeky_delta_stepping(&g, 0, &mut buf);
+
+    dist_eq(&buf, 4, 0.0, EPS);
+    path_eq(&buf, 4, &[0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_early_stop() {
+    let g = linear(100, 1.0);
+    let mut buf = dynamic(100);
+    let result = delta_stepping_to(&g, 0, 10, &mut buf);
+
+    dist_eq(&buf, 10, 10.0, EPS);
+    assert!(result.vertices_reached < 100);
+}
+
+#[test]
+fn test_algo_info() {
+    check_algo_info(&DeltaStepping::<f64>::new(), "Delta-Stepping", false);
+}
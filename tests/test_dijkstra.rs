@@ -91,3 +91,51 @@ fn test_grid() {
 
     dist_eq(&buf, 24, 8.0, EPS); // 4 right + 4 down
 }
+
+#[test]
+fn test_indexed_heap_matches_binary_heap() {
+    use sssp_fast::{DijkstraConfig, IndexedBinaryHeap};
+
+    let g = random::<f64>(300, 3000, 100, 11);
+    let mut expected = dynamic(300);
+    let lazy = Dijkstra::<f64>::new().run(&g, 0, &mut expected);
+
+    for config in [
+        DijkstraConfig::default(),
+        DijkstraConfig::default().without_lazy_deletion(),
+    ] {
+        let mut buf = dynamic(300);
+        let mut algo = Dijkstra::<f64, IndexedBinaryHeap<f64>>::with_config(config);
+        let result = algo.run(&g, 0, &mut buf);
+        assert_eq!(buf.dist, expected.dist);
+        assert_eq!(result.iterations, lazy.iterations);
+    }
+}
+
+#[test]
+fn test_without_lazy_deletion_on_binary_heap() {
+    use sssp_fast::DijkstraConfig;
+
+    // Stale entries are still skipped: one scan per reached vertex
+    let g = complete(30, 1.0);
+    let mut buf = dynamic(30);
+    let config = DijkstraConfig::default().without_lazy_deletion();
+    let result = Dijkstra::<f64>::with_config(config).run(&g, 0, &mut buf);
+    assert_eq!(result.iterations, 30);
+}
+
+#[test]
+fn test_fibonacci_heap_dense() {
+    use sssp_fast::FibonacciHeap;
+
+    let g = random::<f64>(120, 6000, 1000, 2);
+    let mut expected = dynamic(120);
+    Dijkstra::<f64>::new().run(&g, 0, &mut expected);
+
+    let mut buf = dynamic(120);
+    let mut algo = Dijkstra::<f64, FibonacciHeap<f64>>::with_capacity(120);
+    for _ in 0..2 {
+        algo.run(&g, 0, &mut buf);
+        assert_eq!(buf.dist, expected.dist);
+    }
+}
@@ -0,0 +1,185 @@
+mod common;
+
+use common::assertions::EPS_F64 as EPS;
+use common::*;
+use sssp_fast::io::{dimacs, FormatError};
+use sssp_fast::{astar_with, cheeky_dijkstra, AdjListGraph, EuclideanHeuristic, Graph};
+
+const GR: &str = "c 9th DIMACS sample
+c
+p sp 4 5
+a 1 2 10
+a 1 3 4
+a 3 2 3
+a 2 4 1
+a 3 4 20
+";
+
+const CO: &str = "c coordinates
+p aux sp co 4
+v 1 0 0
+v 2 6 0
+v 3 3 1
+v 4 7 0
+";
+
+fn parse_error(result: Result<impl std::fmt::Debug, FormatError>) -> (usize, String) {
+    match result.unwrap_err() {
+        FormatError::Parse { line, message } => (line, message),
+        other => panic!("Expected a parse error, got {other:?}"),
+    }
+}
+
+fn invalid(result: Result<impl std::fmt::Debug, FormatError>) -> String {
+    match result.unwrap_err() {
+        FormatError::Invalid(message) => message,
+        other => panic!("Expected an invalid-file error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_read_gr() {
+    let g: AdjListGraph<f64> = dimacs::read_gr(GR.as_bytes()).unwrap();
+    assert_eq!(g.n(), 4);
+    assert_eq!(g.m(), 5);
+    assert_eq!(g.neighbors(0)[1].to, 2);
+
+    let mut buf = dynamic(4);
+    cheeky_dijkstra(&g, 0, &mut buf);
+    // 1 -> 3 -> 2 -> 4 = 4 + 3 + 1
+    approx_eq(buf.dist[3], 8.0, EPS);
+    path_eq(&buf, 3, &[0, 2, 1, 3]);
+}
+
+#[test]
+fn test_read_co_and_heuristic() {
+    let g: AdjListGraph<f64> = dimacs::read_gr(GR.as_bytes()).unwrap();
+    let coords = dimacs::read_co::<f64, _>(CO.as_bytes()).unwrap();
+    assert_eq!(coords, vec![(0.0, 0.0), (6.0, 0.0), (3.0, 1.0), (7.0, 0.0)]);
+
+    let h = EuclideanHeuristic::fitted(&g, coords);
+    // Tightest edge: 3 -> 2, weight 3 over length sqrt(10)
+    approx_eq(h.scale(), 3.0 / 10f64.sqrt(), EPS);
+
+    let mut a = dynamic(4);
+    let mut b = dynamic(4);
+    astar_with(&g, 0, 3, &h, &mut a);
+    cheeky_dijkstra(&g, 0, &mut b);
+    approx_eq(a.dist[3], b.dist[3], EPS);
+}
+
+#[test]
+fn test_read_queries() {
+    let ss = "c sources\np aux sp ss 2\ns 1\ns 4\n";
+    assert_eq!(dimacs::read_ss(ss.as_bytes(), 4).unwrap(), vec![0, 3]);
+
+    let p2p = "p aux sp p2p 2\nq 1 4\nq 3 2\n";
+    assert_eq!(
+        dimacs::read_p2p(p2p.as_bytes(), 4).unwrap(),
+        vec![(0, 3), (2, 1)]
+    );
+}
+
+#[test]
+fn test_roundtrip() {
+    let g = random::<f64>(30, 120, 50, 9);
+    let mut bytes = Vec::new();
+    dimacs::write_gr(&g, &mut bytes).unwrap();
+    let back: AdjListGraph<f64> = dimacs::read_gr(bytes.as_slice()).unwrap();
+
+    assert_eq!(back.n(), g.n());
+    for u in 0..g.n() {
+        assert_eq!(back.neighbors(u), g.neighbors(u));
+    }
+
+    let coords: Vec<(f64, f64)> = (0..5).map(|i| (i as f64 * 1.5, -(i as f64))).collect();
+    let mut bytes = Vec::new();
+    dimacs::write_co(&coords, &mut bytes).unwrap();
+    assert_eq!(dimacs::read_co::<f64, _>(bytes.as_slice()).unwrap(), coords);
+}
+
+#[test]
+fn test_file_roundtrip() {
+    let path = std::env::temp_dir().join(format!("sssp_fast_dimacs_{}.gr", std::process::id()));
+    let g = grid(3, 3, 2.0);
+    dimacs::write_gr(&g, std::fs::File::create(&path).unwrap()).unwrap();
+    let back: AdjListGraph<f64> = dimacs::read_gr(std::fs::File::open(&path).unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(back.m(), g.m());
+}
+
+#[test]
+fn test_malformed_lines() {
+    let gr = |text: &str| dimacs::read_gr::<f64, _>(text.as_bytes());
+
+    assert_eq!(parse_error(gr("a 1 2 3\n")).0, 1);
+    assert_eq!(
+        parse_error(gr("p sp 2 1\na 1 3 1\n")),
+        (2, "vertex 3 out of range 1..=2".to_string())
+    );
+    assert_eq!(
+        parse_error(gr("p sp 2 1\n\na 1 2 x\n")),
+        (3, "invalid number 'x'".to_string())
+    );
+    assert_eq!(
+        parse_error(gr("p sp 2 1\na 1 2\n")),
+        (2, "missing number".to_string())
+    );
+    assert_eq!(
+        parse_error(gr("p sp 2 1\na 1 2 1 9\n")),
+        (2, "unexpected trailing field '9'".to_string())
+    );
+    assert_eq!(
+        parse_error(gr("p max 2 1\n")),
+        (1, "expected 'sp', found 'max'".to_string())
+    );
+    assert_eq!(
+        parse_error(gr("p sp 2 1\nx 1 2\n")),
+        (2, "unknown line type 'x'".to_string())
+    );
+    assert_eq!(parse_error(gr("p sp 2 0\np sp 2 0\n")).0, 2);
+    assert_eq!(parse_error(gr("p sp 0 1\na 0 1 1\n")).0, 2);
+}
+
+#[test]
+fn test_inconsistent_files() {
+    assert_eq!(
+        invalid(dimacs::read_gr::<f64, _>("p sp 2 2\na 1 2 1\n".as_bytes())),
+        "expected 2 arcs, found 1"
+    );
+    assert_eq!(
+        invalid(dimacs::read_gr::<f64, _>("c empty\n".as_bytes())),
+        "missing problem line"
+    );
+    assert_eq!(
+        invalid(dimacs::read_co::<f64, _>(
+            "p aux sp co 2\nv 1 0 0\n".as_bytes()
+        )),
+        "vertex 2 has no coordinates"
+    );
+    assert_eq!(
+        invalid(dimacs::read_ss("p aux sp ss 3\ns 1\n".as_bytes(), 2)),
+        "expected 3 queries, found 1"
+    );
+}
+
+#[test]
+fn test_huge_vertex_count() {
+    let (line, message) = parse_error(dimacs::read_gr::<f64, _>(
+        "p sp 99999999999999 0\n".as_bytes(),
+    ));
+    assert_eq!(
+        (line, message.as_str()),
+        (1, "vertex count 99999999999999 too large")
+    );
+
+    let co = "c huge\np aux sp co 99999999999999\n";
+    assert_eq!(parse_error(dimacs::read_co::<f64, _>(co.as_bytes())).0, 2);
+}
+
+#[test]
+fn test_error_display() {
+    let err = dimacs::read_gr::<f64, _>("p sp 1 1\na 1 1 ?\n".as_bytes()).unwrap_err();
+    assert_eq!(err.to_string(), "line 2: invalid number '?'");
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use common::*;
+use sssp_fast::io::dot::{mst_to_dot, path_to_dot, sssp_to_dot, to_dot, Dot};
+use sssp_fast::{cheeky_dijkstra, cheeky_floyd_warshall, cheeky_prim, AdjListGraph, ApspBuffers};
+
+fn lines_with<'a>(dot: &'a str, needle: &str) -> Vec<&'a str> {
+    dot.lines().filter(|l| l.contains(needle)).collect()
+}
+
+fn triangle() -> AdjListGraph<f64> {
+    let mut g = AdjListGraph::new(3);
+    g.add_edge(0, 1, 1.0);
+    g.add_edge(1, 2, 2.5);
+    g.add_edge(0, 2, 5.0);
+    g
+}
+
+#[test]
+fn test_plain_graph() {
+    let dot = to_dot(&triangle());
+
+    assert!(dot.starts_with("digraph G {\n"));
+    assert!(dot.ends_with("}\n"));
+    assert!(dot.contains("  1 [label=\"1\"];"));
+    assert!(dot.contains("  1 -> 2 [label=\"2.5\"];"));
+    assert_eq!(lines_with(&dot, "->").len(), 3);
+    assert!(lines_with(&dot, "color").is_empty());
+}
+
+#[test]
+fn test_sssp_tree_and_path() {
+    let g = triangle();
+    let mut buf = dynamic(3);
+    cheeky_dijkstra(&g, 0, &mut buf);
+    let dot = sssp_to_dot(&g, &buf, Some(2));
+
+    // Distances as labels
+    assert!(dot.contains("  2 [label=\"2\\n3.5\", color=\"red\"];"));
+    // 0 -> 1 -> 2 is both tree and path, 0 -> 2 is neither
+    assert!(dot.contains("  0 -> 1 [label=\"1\", color=\"red\", penwidth=3];"));
+    assert!(dot.contains("  1 -> 2 [label=\"2.5\", color=\"red\", penwidth=3];"));
+    assert!(dot.contains("  0 -> 2 [label=\"5\"];"));
+}
+
+#[test]
+fn test_sssp_tree_without_target() {
+    let g = star::<f64>(4, 1.0);
+    let mut buf = dynamic(4);
+    cheeky_dijkstra(&g, 0, &mut buf);
+    let dot = sssp_to_dot(&g, &buf, None);
+
+    assert_eq!(lines_with(&dot, "color=\"blue\"").len(), 3);
+    assert!(lines_with(&dot, "red").is_empty());
+}
+
+#[test]
+fn test_unreachable_label() {
+    let g = disconnected::<f64>(4, 1.0);
+    let mut buf = dynamic(4);
+    cheeky_dijkstra(&g, 0, &mut buf);
+    let dot = sssp_to_dot(&g, &buf, Some(3));
+
+    assert!(dot.contains("  3 [label=\"3\\ninf\"];"));
+    assert!(lines_with(&dot, "red").is_empty());
+}
+
+#[test]
+fn test_mst_undirected() {
+    let g = grid_undirected::<f64>(2, 2, 1.0);
+    let mut buf = mst_dynamic(4);
+    cheeky_prim(&g, 0, &mut buf);
+    let dot = mst_to_dot(&g, &buf);
+
+    assert!(dot.starts_with("graph G {\n"));
+    // Each undirected pair drawn once, 3 of the 4 in the tree
+    assert_eq!(lines_with(&dot, "--").len(), 4);
+    assert_eq!(lines_with(&dot, "color=\"blue\"").len(), 3);
+}
+
+#[test]
+fn test_parallel_edges_highlight_cheapest() {
+    let mut g: AdjListGraph<f64> = AdjListGraph::new(2);
+    g.add_edge(0, 1, 4.0);
+    g.add_edge(0, 1, 2.0);
+    g.add_edge(0, 1, 2.0);
+    let dot = path_to_dot(&g, &[0, 1]);
+
+    assert_eq!(lines_with(&dot, "penwidth").len(), 1);
+    assert!(dot.contains("  0 -> 1 [label=\"2\", color=\"red\", penwidth=3];"));
+}
+
+#[test]
+fn test_apsp_path() {
+    let g = triangle();
+    let mut apsp = ApspBuffers::new(3);
+    cheeky_floyd_warshall(&g, &mut apsp);
+    let path = apsp.path(0, 2).unwrap();
+
+    let row: Vec<f64> = (0..3).map(|j| apsp.get(0, j)).collect();
+    let dot = Dot::new(&g).with_labels(row).with_path(&path).render();
+
+    assert_eq!(lines_with(&dot, "penwidth=3").len(), 2);
+    assert!(dot.contains("  1 [label=\"1\\n1\", color=\"red\"];"));
+}
+
+#[test]
+fn test_write() {
+    let g = triangle();
+    let mut bytes = Vec::new();
+    Dot::new(&g).write(&mut bytes).unwrap();
+    assert_eq!(String::from_utf8(bytes).unwrap(), to_dot(&g));
+}
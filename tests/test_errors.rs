@@ -0,0 +1,187 @@
+mod common;
+
+use common::*;
+use sssp_fast::{
+    AStar, AdjListGraph, ApspAlgorithm, ApspBuffers, BellmanFord, BellmanFordConfig,
+    BidirectionalDijkstra, Dijkstra, DijkstraConfig, FloydWarshall, Graph, Hybrid, Johnson,
+    Kruskal, MstAlgorithm, Prim, SsspAlgorithm, SsspAlgorithmInfo, SsspError, ZeroHeuristic,
+};
+
+fn with_edge(w: f64) -> AdjListGraph<f64> {
+    let mut g = linear::<f64>(4, 1.0);
+    g.add_edge(3, 1, w);
+    g
+}
+
+#[test]
+fn test_valid_input_matches_run() {
+    let g = random::<f64>(40, 200, 10, 5);
+    let mut expected = dynamic(40);
+    let mut buf = dynamic(40);
+
+    let result = Dijkstra::<f64>::new().run(&g, 0, &mut expected);
+    let checked = Dijkstra::<f64>::new().try_run(&g, 0, &mut buf).unwrap();
+    assert_eq!(checked.iterations, result.iterations);
+    assert_eq!(buf.dist, expected.dist);
+}
+
+#[test]
+fn test_source_out_of_bounds() {
+    let g = linear::<f64>(4, 1.0);
+    let mut buf = dynamic(4);
+    let err = Dijkstra::<f64>::new().try_run(&g, 4, &mut buf).unwrap_err();
+    assert_eq!(err, SsspError::SourceOutOfBounds { source: 4, n: 4 });
+
+    let mut mst = mst_dynamic(4);
+    let err = Prim::<f64>::new().try_run(&g, 9, &mut mst).unwrap_err();
+    assert_eq!(err, SsspError::SourceOutOfBounds { source: 9, n: 4 });
+}
+
+#[test]
+fn test_target_out_of_bounds() {
+    let g = linear::<f64>(4, 1.0);
+    let mut buf = dynamic(4);
+
+    let mut dijkstra = Dijkstra::<f64>::with_config(DijkstraConfig::with_target(7));
+    let err = dijkstra.try_run(&g, 0, &mut buf).unwrap_err();
+    assert_eq!(err, SsspError::TargetOutOfBounds { target: 7, n: 4 });
+
+    let mut astar = AStar::<f64, _>::new(10, ZeroHeuristic);
+    let err = astar.try_run(&g, 0, &mut buf).unwrap_err();
+    assert_eq!(err, SsspError::TargetOutOfBounds { target: 10, n: 4 });
+
+    let mut bf = BellmanFord::<f64>::with_config(BellmanFordConfig::with_target(4));
+    assert!(bf.try_run(&g, 0, &mut buf).is_err());
+}
+
+#[test]
+fn test_missing_target() {
+    let g = linear::<f64>(4, 1.0);
+    let mut buf = dynamic(4);
+
+    let mut astar = AStar::<f64, ZeroHeuristic>::default();
+    let err = astar.try_run(&g, 0, &mut buf).unwrap_err();
+    assert_eq!(err, SsspError::MissingTarget);
+
+    // Bidirectional takes its target at construction
+    let mut bidir = BidirectionalDijkstra::<f64>::new(3);
+    assert_eq!(bidir.target(), Some(3));
+    assert!(bidir.try_run(&g, 0, &mut buf).is_ok());
+
+    // Target is optional for plain Dijkstra
+    assert!(Dijkstra::<f64>::new().try_run(&g, 0, &mut buf).is_ok());
+}
+
+#[test]
+fn test_buffer_size_mismatch() {
+    let g = linear::<f64>(4, 1.0);
+    let mut buf = dynamic(3);
+    let err = Dijkstra::<f64>::new().try_run(&g, 0, &mut buf).unwrap_err();
+    assert_eq!(
+        err,
+        SsspError::BufferSizeMismatch {
+            expected: 4,
+            actual: 3
+        }
+    );
+
+    let mut small = fixed::<f64, 2>();
+    assert!(Hybrid::<f64>::new().try_run(&g, 0, &mut small).is_err());
+
+    let mut mst = mst_dynamic(5);
+    assert!(Kruskal::<f64>::new().try_run(&g, 0, &mut mst).is_err());
+
+    let mut apsp_buf = apsp(5);
+    let err = FloydWarshall::<f64>::new()
+        .try_run(&g, &mut apsp_buf)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        SsspError::BufferSizeMismatch {
+            expected: 4,
+            actual: 5
+        }
+    );
+}
+
+#[test]
+fn test_negative_weight() {
+    let g = with_edge(-3.0);
+    let mut buf = dynamic(4);
+
+    let err = Dijkstra::<f64>::new().try_run(&g, 0, &mut buf).unwrap_err();
+    assert_eq!(err, SsspError::NegativeWeight { from: 3, to: 1 });
+    let mut astar = AStar::<f64, _>::new(3, ZeroHeuristic);
+    assert!(astar.try_run(&g, 0, &mut buf).is_err());
+
+    // Algorithms that handle negative weights accept them
+    let result = BellmanFord::<f64>::new().try_run(&g, 0, &mut buf).unwrap();
+    assert!(result.negative_cycle);
+    assert!(Johnson::<f64>::new().try_run(&g, &mut apsp(4)).is_ok());
+    assert!(Prim::<f64>::new()
+        .try_run(&g, 0, &mut mst_dynamic(4))
+        .is_ok());
+}
+
+#[test]
+fn test_nan_weight() {
+    let g = with_edge(f64::NAN);
+    let mut buf = dynamic(4);
+
+    let err = BellmanFord::<f64>::new()
+        .try_run(&g, 0, &mut buf)
+        .unwrap_err();
+    assert_eq!(err, SsspError::NanWeight { from: 3, to: 1 });
+
+    let err = Kruskal::<f64>::new()
+        .try_run(&g, 0, &mut mst_dynamic(4))
+        .unwrap_err();
+    assert_eq!(err, SsspError::NanWeight { from: 3, to: 1 });
+
+    let err = FloydWarshall::<f64>::new()
+        .try_run(&g, &mut apsp(4))
+        .unwrap_err();
+    assert_eq!(err, SsspError::NanWeight { from: 3, to: 1 });
+}
+
+/// Implicit edgeless graph with 2^32 vertices.
+struct Huge;
+
+impl Graph<f64> for Huge {
+    fn n(&self) -> usize {
+        1 << 32
+    }
+
+    fn for_each_out_edge<F: FnMut(usize, f64)>(&self, _u: usize, _f: F) {}
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn test_apsp_matrix_overflow() {
+    // n * n wraps to 0, which would match empty matrices
+    let mut buf = ApspBuffers::<f64> {
+        n: 1 << 32,
+        dist: Vec::new(),
+        next: Vec::new(),
+    };
+    let err = FloydWarshall::<f64>::new()
+        .try_run(&Huge, &mut buf)
+        .unwrap_err();
+    assert_eq!(err, SsspError::MatrixTooLarge { n: 1 << 32 });
+}
+
+#[test]
+fn test_display() {
+    let err = SsspError::SourceOutOfBounds { source: 5, n: 3 };
+    assert_eq!(
+        err.to_string(),
+        "source vertex 5 out of bounds for 3 vertices"
+    );
+    assert_eq!(
+        SsspError::MissingTarget.to_string(),
+        "algorithm requires a target vertex"
+    );
+
+    let boxed: Box<dyn std::error::Error> = Box::new(SsspError::NanWeight { from: 0, to: 1 });
+    assert_eq!(boxed.to_string(), "NaN weight on edge 0 -> 1");
+}
@@ -1,7 +1,7 @@
 mod common;
 
 use common::{assertions::EPS_F64 as EPS, *};
-use sssp_fast::{AdjListGraph, ApspAlgorithm};
+use sssp_fast::{AdjListGraph, ApspAlgorithm, Graph};
 
 #[test]
 fn test_shortest_paths() {
@@ -72,3 +72,54 @@ fn test_negative_cycle() {
 
     assert!(result.negative_cycle);
 }
+
+fn floyd_warshall_detect(g: &AdjListGraph<f64>) -> sssp_fast::ApspResult<f64> {
+    let mut buf = apsp(g.n());
+    sssp_fast::FloydWarshall::<f64>::with_config(sssp_fast::FloydWarshallConfig {
+        detect_negative_cycle: true,
+    })
+    .run(g, &mut buf)
+}
+
+#[test]
+fn test_negative_cycle_extracted() {
+    // Cycle 1 -> 2 -> 3 -> 1 (weight -1), plus a positive cycle 0 <-> 1
+    let mut g: AdjListGraph<f64> = AdjListGraph::new(4);
+    g.add_edge(0, 1, 1.0);
+    g.add_edge(1, 0, 1.0);
+    g.add_edge(1, 2, 2.0);
+    g.add_edge(2, 3, 2.0);
+    g.add_edge(3, 1, -5.0);
+
+    let result = floyd_warshall_detect(&g);
+
+    let cycle = result.cycle.expect("cycle not extracted");
+    negative_cycle_valid(&g, &cycle, EPS);
+    approx_eq(cycle.weight, -1.0, EPS);
+}
+
+#[test]
+fn test_self_loop_cycle_extracted() {
+    let mut g: AdjListGraph<f64> = AdjListGraph::new(2);
+    g.add_edge(0, 1, 1.0);
+    g.add_edge(1, 1, -2.0);
+
+    let cycle = floyd_warshall_detect(&g)
+        .cycle
+        .expect("cycle not extracted");
+    assert_eq!(cycle.vertices, vec![1]);
+    approx_eq(cycle.weight, -2.0, EPS);
+}
+
+#[test]
+fn test_random_negative_cycles() {
+    for seed in 1..30 {
+        let g = random_shifted::<f64>(25, 60, 10, 3.0, seed);
+        let result = floyd_warshall_detect(&g);
+
+        assert_eq!(result.negative_cycle, result.cycle.is_some());
+        if let Some(cycle) = &result.cycle {
+            negative_cycle_valid(&g, cycle, EPS);
+        }
+    }
+}
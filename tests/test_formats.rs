@@ -0,0 +1,225 @@
+mod common;
+
+use common::*;
+use sssp_fast::io::edge_list::{read_edge_list, write_edge_list};
+use sssp_fast::io::matrix_market::{read_matrix_market, write_matrix_market};
+use sssp_fast::io::{FormatError, LoadedGraph, ReadOptions};
+use sssp_fast::{Edge, Graph};
+
+fn mtx(text: &str, options: &ReadOptions<f64>) -> Result<LoadedGraph<f64>, FormatError> {
+    read_matrix_market(text.as_bytes(), options)
+}
+
+fn edges(text: &str, options: &ReadOptions<f64>) -> Result<LoadedGraph<f64>, FormatError> {
+    read_edge_list(text.as_bytes(), options)
+}
+
+fn parse_line(result: Result<LoadedGraph<f64>, FormatError>) -> usize {
+    match result.unwrap_err() {
+        FormatError::Parse { line, .. } => line,
+        other => panic!("Expected a parse error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_mtx_general() {
+    let text = "%%MatrixMarket matrix coordinate real general
+% comment
+3 3 3
+1 2 0.5
+2 3 1.5
+3 1 2
+";
+    let loaded = mtx(text, &ReadOptions::new()).unwrap();
+    let g = loaded.graph;
+
+    assert_eq!(g.n(), 3);
+    assert_eq!(g.m(), 3);
+    assert_eq!(out_edges(&g, 0), vec![(1, 0.5)]);
+    assert_eq!(out_edges(&g, 2), vec![(0, 2.0)]);
+    assert_eq!(loaded.ids, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_mtx_symmetric() {
+    let text = "%%MatrixMarket matrix coordinate integer symmetric
+3 3 3
+2 1 4
+3 2 7
+3 3 1
+";
+    let g = mtx(text, &ReadOptions::new()).unwrap().graph;
+
+    // Off-diagonal mirrored, diagonal once
+    assert_eq!(g.m(), 5);
+    assert_eq!(out_edges(&g, 0), vec![(1, 4.0)]);
+    assert_eq!(out_edges(&g, 1), vec![(0, 4.0), (2, 7.0)]);
+    assert_eq!(out_edges(&g, 2), vec![(1, 7.0), (2, 1.0)]);
+}
+
+#[test]
+fn test_mtx_skew_symmetric() {
+    let text = "%%MatrixMarket matrix coordinate real skew-symmetric\n2 2 1\n2 1 3\n";
+    let g = mtx(text, &ReadOptions::new()).unwrap().graph;
+
+    assert_eq!(out_edges(&g, 1), vec![(0, 3.0)]);
+    assert_eq!(out_edges(&g, 0), vec![(1, -3.0)]);
+}
+
+#[test]
+fn test_mtx_pattern_default_weight() {
+    let text = "%%MatrixMarket matrix coordinate pattern general\n4 4 2\n1 4\n4 2\n";
+    let g = mtx(text, &ReadOptions::new().with_default_weight(2.5))
+        .unwrap()
+        .graph;
+
+    assert_eq!(g.n(), 4);
+    assert_eq!(out_edges(&g, 0), vec![(3, 2.5)]);
+    assert_eq!(out_edges(&g, 3), vec![(1, 2.5)]);
+}
+
+#[test]
+fn test_mtx_remap() {
+    let text = "%%MatrixMarket matrix coordinate real general\n100 100 2\n50 7 1\n7 99 2\n";
+    let loaded = mtx(text, &ReadOptions::new().remapped()).unwrap();
+
+    assert_eq!(loaded.graph.n(), 3);
+    assert_eq!(loaded.ids, vec![50, 7, 99]);
+    assert_eq!(out_edges(&loaded.graph, 0), vec![(1, 1.0)]);
+    assert_eq!(out_edges(&loaded.graph, 1), vec![(2, 2.0)]);
+}
+
+#[test]
+fn test_mtx_errors() {
+    let opts = ReadOptions::new();
+    let header = "%%MatrixMarket matrix coordinate real general\n";
+
+    assert_eq!(
+        parse_line(mtx("%%MatrixMarket matrix array real general\n", &opts)),
+        1
+    );
+    assert_eq!(
+        parse_line(mtx(
+            "%%MatrixMarket matrix coordinate complex general\n",
+            &opts
+        )),
+        1
+    );
+    assert_eq!(
+        parse_line(mtx(
+            "%%MatrixMarket matrix coordinate real hermitian\n",
+            &opts
+        )),
+        1
+    );
+    assert_eq!(parse_line(mtx("1 1 0\n", &opts)), 1);
+    assert_eq!(
+        parse_line(mtx(&format!("{header}2 2 1\n3 1 1\n"), &opts)),
+        3
+    );
+    assert_eq!(parse_line(mtx(&format!("{header}2 2 1\n1 2\n"), &opts)), 3);
+    assert_eq!(parse_line(mtx(&format!("{header}2 2\n"), &opts)), 2);
+
+    match mtx(&format!("{header}2 2 2\n1 2 1\n"), &opts).unwrap_err() {
+        FormatError::Invalid(message) => assert_eq!(message, "expected 2 entries, found 1"),
+        other => panic!("Unexpected {other:?}"),
+    }
+}
+
+#[test]
+fn test_mtx_roundtrip() {
+    let g = random::<f64>(25, 90, 40, 2);
+    let mut bytes = Vec::new();
+    write_matrix_market(&g, &mut bytes).unwrap();
+    let back = read_matrix_market::<f64, _>(bytes.as_slice(), &ReadOptions::new())
+        .unwrap()
+        .graph;
+
+    assert_eq!(back.n(), g.n());
+    for u in 0..g.n() {
+        assert_eq!(back.neighbors(u), g.neighbors(u));
+    }
+}
+
+#[test]
+fn test_edge_list_snap() {
+    let text = "# Directed graph: sample
+# FromNodeId\tToNodeId
+0\t1
+1\t2
+2\t0
+";
+    let loaded = edges(text, &ReadOptions::new()).unwrap();
+    assert_eq!(loaded.graph.n(), 3);
+    assert_eq!(out_edges(&loaded.graph, 2), vec![(0, 1.0)]);
+    assert_eq!(loaded.ids, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_edge_list_csv_weighted_symmetric() {
+    let text = "% csv\n0,3,1.5\n3, 1, 2\n";
+    let g = edges(text, &ReadOptions::new().symmetric()).unwrap().graph;
+
+    assert_eq!(g.n(), 4);
+    assert_eq!(out_edges(&g, 3), vec![(0, 1.5), (1, 2.0)]);
+    assert_eq!(out_edges(&g, 1), vec![(3, 2.0)]);
+}
+
+#[test]
+fn test_edge_list_remap() {
+    let text = "1000000 42\n42 7 3\n7 1000000\n";
+    let loaded = edges(text, &ReadOptions::new().remapped()).unwrap();
+
+    assert_eq!(loaded.graph.n(), 3);
+    assert_eq!(loaded.ids, vec![1_000_000, 42, 7]);
+    assert_eq!(loaded.graph.neighbors(1), &[Edge::new(2, 3.0)]);
+    assert_eq!(loaded.graph.neighbors(2), &[Edge::new(0, 1.0)]);
+}
+
+#[test]
+fn test_edge_list_errors() {
+    let opts = ReadOptions::new();
+    assert_eq!(parse_line(edges("0 1\n5\n", &opts)), 2);
+    assert_eq!(parse_line(edges("0 1 2 3\n", &opts)), 1);
+    assert_eq!(parse_line(edges("\n\n-1 2\n", &opts)), 3);
+    assert_eq!(parse_line(edges("0 1 heavy\n", &opts)), 1);
+
+    let err = edges("0 x\n", &opts).unwrap_err();
+    assert_eq!(err.to_string(), "line 1: invalid vertex id 'x'");
+}
+
+#[test]
+fn test_huge_ids_rejected() {
+    let opts = ReadOptions::new();
+    let too_large = |result: Result<LoadedGraph<f64>, FormatError>| match result.unwrap_err() {
+        FormatError::Invalid(message) => assert_eq!(message, "vertex count too large"),
+        other => panic!("Unexpected {other:?}"),
+    };
+
+    too_large(edges("0 99999999999999\n", &opts));
+    too_large(edges(&format!("0 {}\n", usize::MAX), &opts));
+    too_large(mtx(
+        "%%MatrixMarket matrix coordinate real general\n99999999999999 99999999999999 0\n",
+        &opts,
+    ));
+
+    // Remapped ids only allocate what was seen
+    let loaded = edges("0 99999999999999\n", &ReadOptions::new().remapped()).unwrap();
+    assert_eq!(loaded.ids, vec![0, 99999999999999]);
+}
+
+#[test]
+fn test_edge_list_roundtrip() {
+    let g = random::<f64>(20, 70, 30, 6);
+    for delimiter in [' ', ',', '\t'] {
+        let mut bytes = Vec::new();
+        write_edge_list(&g, &mut bytes, delimiter).unwrap();
+        let back = read_edge_list::<f64, _>(bytes.as_slice(), &ReadOptions::new())
+            .unwrap()
+            .graph;
+
+        for u in 0..back.n() {
+            assert_eq!(back.neighbors(u), g.neighbors(u));
+        }
+    }
+}
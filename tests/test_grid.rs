@@ -0,0 +1,183 @@
+mod common;
+
+use common::assertions::EPS_F64 as EPS;
+use common::*;
+use sssp_fast::{
+    astar_with, cheeky_dijkstra, Connectivity, DiagonalMovement, Graph, GridGraph, Heuristic,
+    ManhattanHeuristic, OctileHeuristic,
+};
+
+fn neighbours(g: &GridGraph<f64>, row: usize, col: usize) -> Vec<(usize, f64)> {
+    let mut out = Vec::new();
+    g.for_each_out_edge(g.vertex(row, col), |v, w| out.push((v, w)));
+    out.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    out
+}
+
+/// Deterministic scattered walls (xorshift), keeping the corners open.
+fn walled(rows: usize, cols: usize, seed: u64) -> GridGraph<f64> {
+    let mut g = GridGraph::new(rows, cols);
+    let mut state = seed;
+    for r in 0..rows {
+        for c in 0..cols {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let corner = (r, c) == (0, 0) || (r, c) == (rows - 1, cols - 1);
+            if !corner && state.is_multiple_of(4) {
+                g.set_wall(r, c, true);
+            }
+            g.set_cost(r, c, 1.0 + (state % 3) as f64);
+        }
+    }
+    g
+}
+
+#[test]
+fn test_four_connectivity() {
+    let g: GridGraph<f64> = GridGraph::new(3, 3);
+
+    assert_eq!(g.n(), 9);
+    assert_eq!(neighbours(&g, 0, 0), vec![(1, 1.0), (3, 1.0)]);
+    assert_eq!(neighbours(&g, 1, 1).len(), 4);
+    assert_eq!(g.cell(5), (1, 2));
+}
+
+#[test]
+fn test_eight_connectivity() {
+    let g: GridGraph<f64> = GridGraph::new(3, 3).with_connectivity(Connectivity::Eight);
+    let sqrt2 = std::f64::consts::SQRT_2;
+
+    assert_eq!(neighbours(&g, 1, 1).len(), 8);
+    assert_eq!(neighbours(&g, 0, 0), vec![(1, 1.0), (3, 1.0), (4, sqrt2)]);
+}
+
+#[test]
+fn test_walls_block() {
+    let mut g: GridGraph<f64> = GridGraph::new(3, 3);
+    g.set_wall(0, 1, true);
+
+    assert!(g.is_wall(0, 1));
+    assert_eq!(neighbours(&g, 0, 0), vec![(3, 1.0)]);
+    assert!(neighbours(&g, 0, 1).is_empty());
+
+    g.set_wall(0, 1, false);
+    assert_eq!(neighbours(&g, 0, 0).len(), 2);
+}
+
+#[test]
+fn test_corner_cutting_rules() {
+    let diagonal_ok = |rule, walls: &[(usize, usize)]| {
+        let mut g: GridGraph<f64> = GridGraph::new(2, 2)
+            .with_connectivity(Connectivity::Eight)
+            .with_diagonal(rule);
+        for &(r, c) in walls {
+            g.set_wall(r, c, true);
+        }
+        neighbours(&g, 0, 0).iter().any(|&(v, _)| v == 3)
+    };
+
+    // One orthogonal cell blocked
+    assert!(diagonal_ok(DiagonalMovement::Always, &[(0, 1)]));
+    assert!(diagonal_ok(DiagonalMovement::IfOneFree, &[(0, 1)]));
+    assert!(!diagonal_ok(DiagonalMovement::IfBothFree, &[(0, 1)]));
+
+    // Squeezing between two walls
+    assert!(diagonal_ok(DiagonalMovement::Always, &[(0, 1), (1, 0)]));
+    assert!(!diagonal_ok(DiagonalMovement::IfOneFree, &[(0, 1), (1, 0)]));
+
+    assert!(diagonal_ok(DiagonalMovement::IfBothFree, &[]));
+}
+
+#[test]
+fn test_cell_costs() {
+    let mut g: GridGraph<f64> = GridGraph::new(1, 3);
+    g.set_cost(0, 1, 5.0);
+    g.set_cost(0, 2, 2.0);
+
+    // Entering a cell pays its cost
+    assert_eq!(neighbours(&g, 0, 0), vec![(1, 5.0)]);
+    assert_eq!(neighbours(&g, 0, 2), vec![(1, 5.0)]);
+    assert_eq!(neighbours(&g, 0, 1), vec![(0, 1.0), (2, 2.0)]);
+    approx_eq(g.min_cost(), 1.0, EPS);
+
+    let mut buf = dynamic(3);
+    cheeky_dijkstra(&g, 0, &mut buf);
+    approx_eq(buf.dist[2], 7.0, EPS);
+}
+
+#[test]
+fn test_matches_adjacency_grid() {
+    let g: GridGraph<f64> = GridGraph::new(6, 7);
+    let adj = grid_undirected(6, 7, 1.0);
+
+    let mut a = dynamic(42);
+    let mut b = dynamic(42);
+    cheeky_dijkstra(&g, 0, &mut a);
+    cheeky_dijkstra(&adj, 0, &mut b);
+    assert_eq!(a.dist, b.dist);
+}
+
+#[test]
+fn test_manhattan_astar() {
+    for seed in 1..8 {
+        let g = walled(15, 15, seed);
+        let h = ManhattanHeuristic::for_grid(&g);
+        let target = g.vertex(14, 14);
+
+        let mut a = dynamic(225);
+        let mut b = dynamic(225);
+        astar_with(&g, 0, target, h, &mut a);
+        cheeky_dijkstra(&g, 0, &mut b);
+        assert_eq!(a.dist[target], b.dist[target]);
+    }
+}
+
+#[test]
+fn test_octile_astar() {
+    for rule in [
+        DiagonalMovement::Always,
+        DiagonalMovement::IfOneFree,
+        DiagonalMovement::IfBothFree,
+    ] {
+        let g = walled(15, 15, 3)
+            .with_connectivity(Connectivity::Eight)
+            .with_diagonal(rule);
+        let h = OctileHeuristic::for_grid(&g);
+        let target = g.vertex(14, 14);
+
+        let mut a = dynamic(225);
+        let mut b = dynamic(225);
+        astar_with(&g, 0, target, h, &mut a);
+        cheeky_dijkstra(&g, 0, &mut b);
+        approx_eq(a.dist[target], b.dist[target], EPS);
+    }
+}
+
+#[test]
+fn test_heuristics_admissible() {
+    let four = walled(8, 8, 5);
+    let eight = walled(8, 8, 5).with_connectivity(Connectivity::Eight);
+    let target = 63;
+
+    for v in 0..64 {
+        let mut buf = dynamic(64);
+        cheeky_dijkstra(&four, v, &mut buf);
+        assert!(ManhattanHeuristic::for_grid(&four).estimate(v, target) <= buf.dist[target] + EPS);
+
+        cheeky_dijkstra(&eight, v, &mut buf);
+        assert!(OctileHeuristic::for_grid(&eight).estimate(v, target) <= buf.dist[target] + EPS);
+    }
+}
+
+#[test]
+fn test_octile_exact_on_open_grid() {
+    let g: GridGraph<f64> = GridGraph::new(10, 10).with_connectivity(Connectivity::Eight);
+    let h = OctileHeuristic::for_grid(&g);
+
+    let mut buf = dynamic(100);
+    cheeky_dijkstra(&g, 0, &mut buf);
+    for v in 0..100 {
+        approx_eq(h.estimate(0, v), buf.dist[v], 1e-9);
+    }
+}
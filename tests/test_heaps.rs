@@ -1,4 +1,36 @@
-use sssp_fast::{BinaryHeap, FibonacciHeap, PairingHeap, PriorityQueue};
+mod common;
+
+use common::*;
+use sssp_fast::{
+    AddressablePriorityQueue, AdjListGraph, BinaryHeap, BucketQueue, DaryHeap, Dijkstra,
+    FibonacciHeap, Graph, IndexedBinaryHeap, PairingHeap, PriorityQueue, RadixHeap, SsspAlgorithm,
+};
+
+/// Pops until empty, checking keys come out non-decreasing.
+fn drain_sorted<H: PriorityQueue<f64>>(heap: &mut H) -> Vec<f64> {
+    let mut out = Vec::new();
+    while let Some(e) = heap.pop() {
+        if let Some(&last) = out.last() {
+            assert!(e.dist >= last, "{} popped after {}", e.dist, last);
+        }
+        out.push(e.dist);
+    }
+    out
+}
+
+/// Dijkstra with queue `H` must reproduce binary-heap distances.
+fn dijkstra_matches<H: PriorityQueue<f64>>(g: &AdjListGraph<f64>, heap: H) {
+    let n = g.n();
+    let mut expected = dynamic(n);
+    Dijkstra::<f64>::new().run(g, 0, &mut expected);
+
+    let mut algo = Dijkstra::with_heap(heap);
+    for _ in 0..2 {
+        let mut buf = dynamic(n);
+        algo.run(g, 0, &mut buf);
+        assert_eq!(buf.dist, expected.dist);
+    }
+}
 
 mod binary_heap {
     use super::*;
@@ -90,6 +122,130 @@ mod fibonacci_heap {
         assert_eq!(heap.pop().unwrap().dist, 4.0);
         assert_eq!(heap.pop().unwrap().dist, 5.0);
     }
+
+    #[test]
+    fn test_handle_decrease() {
+        let mut heap = FibonacciHeap::<f64>::new();
+        let handles: Vec<_> = (0..10).map(|i| heap.insert(10.0 + i as f64, i)).collect();
+
+        // Build some trees first so decreases cut real children
+        assert_eq!(heap.pop().unwrap().vertex, 0);
+        heap.decrease(handles[7], 1.0);
+        heap.decrease(handles[9], 2.0);
+        heap.decrease(handles[8], 50.0); // Ignored
+
+        assert_eq!(heap.get(handles[8]).unwrap().dist, 18.0);
+        assert_eq!(heap.pop().unwrap().vertex, 7);
+        assert_eq!(heap.pop().unwrap().vertex, 9);
+        assert!(heap.get(handles[7]).is_none());
+        assert_eq!(heap.len(), 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "Stale")]
+    fn test_stale_handle() {
+        let mut heap = FibonacciHeap::<f64>::new();
+        let h = heap.insert(1.0, 0);
+        heap.pop();
+        heap.insert(2.0, 1); // Reuses the slot
+        heap.decrease(h, 0.0);
+    }
+
+    #[test]
+    fn test_clear_invalidates_handles() {
+        let mut heap = FibonacciHeap::<f64>::new();
+        let h = heap.insert(1.0, 0);
+        heap.clear();
+        assert!(heap.get(h).is_none());
+        assert!(!heap.contains(0));
+    }
+
+    #[test]
+    fn test_meld() {
+        let mut a = FibonacciHeap::<f64>::new();
+        let mut b = FibonacciHeap::<f64>::new();
+        for i in 0..20 {
+            a.push((2 * i) as f64, 2 * i);
+            b.push((2 * i + 1) as f64, 2 * i + 1);
+        }
+        a.pop();
+        b.pop();
+
+        a.meld(b);
+        assert_eq!(a.len(), 38);
+        a.decrease_key(-1.0, 39);
+        assert_eq!(a.pop().unwrap().vertex, 39);
+        for i in 2..39 {
+            assert_eq!(a.pop().unwrap().vertex, i);
+        }
+        assert!(a.is_empty());
+    }
+
+    #[test]
+    fn test_meld_into_empty() {
+        let mut a = FibonacciHeap::<f64>::new();
+        let mut b = FibonacciHeap::<f64>::new();
+        b.push(3.0, 1);
+        b.push(2.0, 0);
+        a.meld(b);
+        assert_eq!(a.priority(1), Some(3.0));
+        assert_eq!(a.pop().unwrap().vertex, 0);
+    }
+
+    #[test]
+    fn test_push_moves_existing_vertex() {
+        let mut heap = FibonacciHeap::<f64>::new();
+        for i in 0..8 {
+            heap.push(i as f64, i);
+        }
+        heap.pop(); // Consolidate into trees
+        heap.push(100.0, 1); // Raise
+        heap.push(0.5, 6); // Lower
+
+        assert_eq!(heap.len(), 7);
+        assert_eq!(heap.pop().unwrap().vertex, 6);
+        let order: Vec<usize> = std::iter::from_fn(|| heap.pop().map(|e| e.vertex)).collect();
+        assert_eq!(order, vec![2, 3, 4, 5, 7, 1]);
+    }
+
+    #[test]
+    fn test_random_ops_match_reference() {
+        let n = 300;
+        let mut heap = FibonacciHeap::<f64>::with_capacity(n);
+        let mut keys: Vec<Option<f64>> = vec![None; n];
+        let mut state = 987654321u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for _ in 0..20000 {
+            let v = (next() % n as u64) as usize;
+            let d = (next() % 10000) as f64;
+            match next() % 5 {
+                0 => {
+                    heap.push(d, v);
+                    keys[v] = Some(d);
+                }
+                1 | 2 => {
+                    heap.decrease_key(d, v);
+                    keys[v] = Some(keys[v].map_or(d, |k| if d < k { d } else { k }));
+                }
+                _ => {
+                    let expected = keys.iter().flatten().copied().reduce(f64::min);
+                    let popped = heap.pop();
+                    assert_eq!(popped.map(|e| e.dist), expected);
+                    if let Some(e) = popped {
+                        assert_eq!(keys[e.vertex], Some(e.dist));
+                        keys[e.vertex] = None;
+                    }
+                }
+            }
+            assert_eq!(heap.len(), keys.iter().flatten().count());
+        }
+    }
 }
 
 mod pairing_heap {
@@ -161,3 +317,533 @@ mod pairing_heap {
         assert!(heap.is_empty());
     }
 }
+
+mod indexed_binary_heap {
+    use super::*;
+
+    #[test]
+    fn test_min_heap_order() {
+        let mut heap = IndexedBinaryHeap::<f64>::new();
+        heap.push(5.0, 0);
+        heap.push(1.0, 1);
+        heap.push(3.0, 2);
+
+        assert_eq!(heap.pop().unwrap().dist, 1.0);
+        assert_eq!(heap.pop().unwrap().dist, 3.0);
+        assert_eq!(heap.pop().unwrap().dist, 5.0);
+        assert!(heap.pop().is_none());
+    }
+
+    #[test]
+    fn test_decrease_key() {
+        let mut heap = IndexedBinaryHeap::<f64>::with_capacity(4);
+        heap.push(5.0, 0);
+        heap.push(4.0, 1);
+        heap.push(3.0, 2);
+
+        heap.decrease_key(1.0, 0);
+        assert_eq!(heap.len(), 3);
+        assert_eq!(heap.priority(0), Some(1.0));
+
+        // Larger key is ignored
+        heap.decrease_key(9.0, 2);
+        assert_eq!(heap.priority(2), Some(3.0));
+
+        let e = heap.pop().unwrap();
+        assert_eq!((e.dist, e.vertex), (1.0, 0));
+        assert!(!heap.contains(0));
+        assert_eq!(heap.priority(0), None);
+    }
+
+    #[test]
+    fn test_push_updates_existing() {
+        let mut heap = IndexedBinaryHeap::<f64>::new();
+        heap.push(1.0, 7);
+        heap.push(2.0, 3);
+        heap.push(5.0, 7); // Raise
+
+        assert_eq!(heap.len(), 2);
+        assert_eq!(heap.pop().unwrap().vertex, 3);
+        assert_eq!(heap.pop().unwrap().dist, 5.0);
+    }
+
+    #[test]
+    fn test_decrease_key_inserts_absent() {
+        let mut heap = IndexedBinaryHeap::<f64>::new();
+        heap.decrease_key(2.0, 10);
+        assert!(heap.contains(10));
+        assert!(!heap.contains(11));
+        assert_eq!(heap.peek().unwrap().vertex, 10);
+    }
+
+    #[test]
+    fn test_clear_forgets_positions() {
+        let mut heap = IndexedBinaryHeap::<f64>::new();
+        heap.push(1.0, 0);
+        heap.push(2.0, 1);
+        heap.clear();
+
+        assert!(heap.is_empty());
+        assert!(!heap.contains(0));
+        heap.push(3.0, 1);
+        assert_eq!(heap.len(), 1);
+    }
+
+    #[test]
+    fn test_random_ops_match_reference() {
+        let n = 200;
+        let mut heap = IndexedBinaryHeap::<f64>::with_capacity(n);
+        let mut keys: Vec<Option<f64>> = vec![None; n];
+        let mut state = 12345u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for _ in 0..5000 {
+            let v = (next() % n as u64) as usize;
+            let d = (next() % 1000) as f64;
+            match next() % 3 {
+                0 => {
+                    heap.push(d, v);
+                    keys[v] = Some(d);
+                }
+                1 => {
+                    heap.decrease_key(d, v);
+                    keys[v] = Some(keys[v].map_or(d, |k| if d < k { d } else { k }));
+                }
+                _ => {
+                    let expected = keys.iter().flatten().copied().reduce(f64::min);
+                    let popped = heap.pop();
+                    assert_eq!(popped.map(|e| e.dist), expected);
+                    if let Some(e) = popped {
+                        assert_eq!(keys[e.vertex], Some(e.dist));
+                        keys[e.vertex] = None;
+                    }
+                }
+            }
+            assert_eq!(heap.len(), keys.iter().flatten().count());
+        }
+    }
+}
+
+mod dary_heap {
+    use super::*;
+
+    #[test]
+    fn test_min_heap_order() {
+        let mut heap = DaryHeap::<f64>::new();
+        assert_eq!(heap.arity(), 4);
+        for (i, k) in [5.0, 1.0, 3.0, 8.0, 2.0, 9.0, 0.5].into_iter().enumerate() {
+            heap.push(k, i);
+        }
+        assert_eq!(heap.peek().unwrap().dist, 0.5);
+        assert_eq!(
+            drain_sorted(&mut heap),
+            vec![0.5, 1.0, 2.0, 3.0, 5.0, 8.0, 9.0]
+        );
+    }
+
+    #[test]
+    fn test_decrease_key() {
+        let mut heap = DaryHeap::<f64, 3>::with_capacity(8);
+        for v in 0..8 {
+            heap.push(10.0 + v as f64, v);
+        }
+        heap.decrease_key(1.0, 7);
+        heap.decrease_key(20.0, 0); // Larger key is ignored
+        assert_eq!(heap.len(), 8);
+        assert_eq!(heap.priority(0), Some(10.0));
+
+        let e = heap.pop().unwrap();
+        assert_eq!((e.dist, e.vertex), (1.0, 7));
+        assert!(!heap.contains(7));
+    }
+
+    #[test]
+    fn test_push_updates_existing() {
+        let mut heap = DaryHeap::<f64, 8>::new();
+        heap.push(1.0, 7);
+        heap.push(2.0, 3);
+        heap.push(5.0, 7); // Raise
+
+        assert_eq!(heap.len(), 2);
+        assert_eq!(heap.pop().unwrap().vertex, 3);
+        assert_eq!(heap.pop().unwrap().dist, 5.0);
+    }
+
+    #[test]
+    fn test_clear_forgets_positions() {
+        let mut heap = DaryHeap::<f64>::new();
+        heap.push(1.0, 0);
+        heap.push(2.0, 1);
+        heap.clear();
+
+        assert!(heap.is_empty());
+        assert!(!heap.contains(0));
+        heap.push(3.0, 1);
+        assert_eq!(heap.len(), 1);
+    }
+
+    fn random_ops_match_reference<const D: usize>() {
+        let n = 200;
+        let mut heap = DaryHeap::<f64, D>::with_capacity(n);
+        let mut keys: Vec<Option<f64>> = vec![None; n];
+        let mut state = 12345u64 + D as u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for _ in 0..5000 {
+            let v = (next() % n as u64) as usize;
+            let d = (next() % 1000) as f64;
+            match next() % 3 {
+                0 => {
+                    heap.push(d, v);
+                    keys[v] = Some(d);
+                }
+                1 => {
+                    heap.decrease_key(d, v);
+                    keys[v] = Some(keys[v].map_or(d, |k| if d < k { d } else { k }));
+                }
+                _ => {
+                    let expected = keys.iter().flatten().copied().reduce(f64::min);
+                    let popped = heap.pop();
+                    assert_eq!(popped.map(|e| e.dist), expected);
+                    if let Some(e) = popped {
+                        assert_eq!(keys[e.vertex], Some(e.dist));
+                        keys[e.vertex] = None;
+                    }
+                }
+            }
+            assert_eq!(heap.len(), keys.iter().flatten().count());
+        }
+    }
+
+    #[test]
+    fn test_random_ops_match_reference() {
+        random_ops_match_reference::<2>();
+        random_ops_match_reference::<3>();
+        random_ops_match_reference::<4>();
+        random_ops_match_reference::<16>();
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let g = random::<f64>(500, 4000, 100, 4);
+        dijkstra_matches(&g, DaryHeap::<f64, 2>::new());
+        dijkstra_matches(&g, DaryHeap::<f64, 4>::new());
+        dijkstra_matches(&g, DaryHeap::<f64, 8>::new());
+    }
+}
+
+mod radix_heap {
+    use super::*;
+
+    #[test]
+    fn test_min_heap_order() {
+        let mut heap = RadixHeap::<f64>::new();
+        heap.push(5.0, 0);
+        heap.push(1.0, 1);
+        heap.push(3.0, 2);
+
+        assert_eq!(heap.pop().unwrap().dist, 1.0);
+        assert_eq!(heap.pop().unwrap().dist, 3.0);
+        assert_eq!(heap.pop().unwrap().dist, 5.0);
+        assert!(heap.pop().is_none());
+    }
+
+    #[test]
+    fn test_monotone_interleaved() {
+        let mut heap = RadixHeap::<f64>::new();
+        heap.push(0.0, 0);
+        heap.push(2.5, 1);
+        assert_eq!(heap.pop().unwrap().dist, 0.0);
+
+        // Pushes at or above the last popped key
+        heap.push(0.0, 2);
+        heap.push(1e-9, 3);
+        heap.push(1e12, 4);
+        assert_eq!(drain_sorted(&mut heap), vec![0.0, 1e-9, 2.5, 1e12]);
+    }
+
+    #[test]
+    fn test_fractional_keys_exact() {
+        let mut heap = RadixHeap::<f64>::new();
+        let keys = [0.3, 0.1, 0.2, 0.30000000000000004, 7.75, 0.1];
+        for (i, &k) in keys.iter().enumerate() {
+            heap.push(k, i);
+        }
+        let mut sorted = keys.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(drain_sorted(&mut heap), sorted);
+    }
+
+    #[test]
+    fn test_clear_resets_floor() {
+        let mut heap = RadixHeap::<f64>::new();
+        heap.push(10.0, 0);
+        heap.pop();
+        heap.clear();
+        heap.push(1.0, 1); // Below the old last key
+        assert_eq!(heap.pop().unwrap().dist, 1.0);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "below last popped")]
+    fn test_non_monotone_push() {
+        let mut heap = RadixHeap::<f64>::new();
+        heap.push(5.0, 0);
+        heap.pop();
+        heap.push(4.0, 1);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        dijkstra_matches(&random::<f64>(500, 4000, 100, 3), RadixHeap::new());
+
+        let mut g = random::<f64>(300, 2000, 1000, 9);
+        g.add_edge(0, 1, 0.001);
+        dijkstra_matches(&g, RadixHeap::new());
+    }
+
+    #[test]
+    fn test_f32() {
+        let mut heap = RadixHeap::<f32>::new();
+        heap.push(2.5, 0);
+        heap.push(0.5, 1);
+        assert_eq!(heap.pop().unwrap().vertex, 1);
+        assert_eq!(heap.len(), 1);
+    }
+}
+
+mod bucket_queue {
+    use super::*;
+
+    #[test]
+    fn test_integer_keys_in_order() {
+        let mut heap = BucketQueue::<f64>::new();
+        for (i, k) in [7.0, 3.0, 0.0, 3.0, 12.0].into_iter().enumerate() {
+            heap.push(k, i);
+        }
+        assert_eq!(heap.len(), 5);
+        assert_eq!(drain_sorted(&mut heap), vec![0.0, 3.0, 3.0, 7.0, 12.0]);
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn test_width_groups_keys() {
+        let mut heap = BucketQueue::<f64>::with_max_weight(2.0, 0.5);
+        assert_eq!(heap.width(), 0.5);
+        heap.push(1.2, 0);
+        heap.push(0.3, 1);
+        heap.push(1.1, 2);
+
+        assert_eq!(heap.pop().unwrap().vertex, 1);
+        // 1.1 and 1.2 share bucket [1.0, 1.5): any order
+        let mut rest = [heap.pop().unwrap().vertex, heap.pop().unwrap().vertex];
+        rest.sort();
+        assert_eq!(rest, [0, 2]);
+    }
+
+    #[test]
+    fn test_push_into_current_bucket() {
+        let mut heap = BucketQueue::<f64>::new();
+        heap.push(4.0, 0);
+        heap.push(9.0, 1);
+        assert_eq!(heap.pop().unwrap().dist, 4.0);
+        heap.push(4.0, 2);
+        heap.push(5.0, 3);
+        assert_eq!(drain_sorted(&mut heap), vec![4.0, 5.0, 9.0]);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut heap = BucketQueue::<f64>::new();
+        heap.push(50.0, 0);
+        heap.pop();
+        heap.clear();
+        heap.push(1.0, 1);
+        assert_eq!(heap.pop().unwrap().dist, 1.0);
+        assert!(heap.pop().is_none());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "below current bucket")]
+    fn test_non_monotone_push() {
+        let mut heap = BucketQueue::<f64>::new();
+        heap.push(5.0, 0);
+        heap.pop();
+        heap.push(3.0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "positive")]
+    fn test_zero_width() {
+        BucketQueue::<f64>::with_max_weight(1.0, 0.0);
+    }
+
+    #[test]
+    fn test_dijkstra_integer_weights() {
+        dijkstra_matches(&random::<f64>(500, 4000, 20, 5), BucketQueue::new());
+    }
+
+    #[test]
+    fn test_dijkstra_scaled_weights() {
+        // Weights k * 0.25, width = smallest positive weight
+        let base = random::<f64>(400, 3000, 40, 6);
+        let mut g = AdjListGraph::new(400);
+        for u in 0..400 {
+            for e in base.neighbors(u) {
+                g.add_edge(u, e.to, e.w * 0.25);
+            }
+        }
+        dijkstra_matches(&g, BucketQueue::with_max_weight(10.0, 0.25));
+    }
+
+    #[test]
+    fn test_dijkstra_wide_buckets_converge() {
+        dijkstra_matches(
+            &random::<f64>(300, 2500, 50, 8),
+            BucketQueue::with_max_weight(50.0, 16.0),
+        );
+    }
+
+    #[test]
+    fn test_ring_wraps_around() {
+        let mut heap = BucketQueue::<f64>::with_max_weight(3.0, 1.0);
+        assert_eq!(heap.num_buckets(), 4);
+        heap.push(0.0, 0);
+        heap.push(2.0, 1);
+
+        // Keys keep cycling through all four slots
+        let mut popped = Vec::new();
+        for i in 0..20 {
+            let e = heap.pop().unwrap();
+            popped.push(e.dist);
+            heap.push(e.dist + 3.0, i);
+        }
+        assert!(popped.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(popped[..6], [0.0, 2.0, 3.0, 5.0, 6.0, 8.0]);
+        assert_eq!(heap.len(), 2);
+    }
+
+    #[test]
+    fn test_large_weights_bounded_memory() {
+        // Distances reach 1e9, but only max_weight / width + 1 buckets exist
+        let g = linear::<f64>(1000, 1e6);
+        let heap = BucketQueue::with_max_weight(1e6, 1e3);
+        assert_eq!(heap.num_buckets(), 1001);
+        dijkstra_matches(&g, heap);
+
+        let mut g = AdjListGraph::new(2);
+        g.add_edge(0, 1, 1e9);
+        let mut buf = dynamic(2);
+        Dijkstra::with_heap(BucketQueue::with_max_weight(1e9, 1e6)).run(&g, 0, &mut buf);
+        assert_eq!(buf.dist[1], 1e9);
+    }
+
+    #[test]
+    #[should_panic(expected = "beyond max_weight")]
+    fn test_weight_beyond_span() {
+        let mut g = AdjListGraph::new(2);
+        g.add_edge(0, 1, 1e9);
+        let mut buf = dynamic(2);
+        Dijkstra::<f64, BucketQueue<f64>>::new().run(&g, 0, &mut buf);
+    }
+
+    #[test]
+    #[should_panic(expected = "too large")]
+    fn test_key_too_large() {
+        BucketQueue::<f64>::new().push(1e300, 0);
+    }
+}
+
+mod ordering {
+    use super::*;
+
+    /// Equal keys pop by vertex; NaN pops after +inf.
+    fn check_total_order<H: PriorityQueue<f64>>() {
+        let mut heap = H::new();
+        for (v, d) in [
+            (5, 1.0),
+            (2, f64::NAN),
+            (9, 1.0),
+            (0, f64::INFINITY),
+            (3, 1.0),
+            (7, 0.5),
+            (1, 1.0),
+        ] {
+            heap.push(d, v);
+        }
+
+        let mut order = Vec::new();
+        while let Some(e) = heap.pop() {
+            order.push(e.vertex);
+        }
+        assert_eq!(order, vec![7, 1, 3, 5, 9, 0, 2]);
+    }
+
+    #[test]
+    fn test_all_comparison_heaps() {
+        check_total_order::<BinaryHeap<f64>>();
+        check_total_order::<PairingHeap<f64>>();
+        check_total_order::<FibonacciHeap<f64>>();
+        check_total_order::<IndexedBinaryHeap<f64>>();
+        check_total_order::<DaryHeap<f64, 4>>();
+    }
+
+    #[test]
+    fn test_decrease_key_from_nan() {
+        let mut heap = FibonacciHeap::<f64>::new();
+        heap.push(f64::NAN, 0);
+        heap.push(3.0, 1);
+        heap.decrease_key(1.0, 0);
+        assert_eq!(heap.pop().unwrap().vertex, 0);
+
+        let mut heap = IndexedBinaryHeap::<f64>::new();
+        heap.push(3.0, 1);
+        heap.push(f64::NAN, 0);
+        heap.decrease_key(1.0, 0);
+        assert_eq!(heap.priority(0), Some(1.0));
+        assert_eq!(heap.pop().unwrap().vertex, 0);
+    }
+
+    #[test]
+    fn test_entry_order() {
+        use sssp_fast::HeapEntry;
+
+        let e = HeapEntry::new;
+        assert!(e(1.0, 9) < e(2.0, 0));
+        assert!(e(1.0, 0) < e(1.0, 1));
+        assert!(e(f64::INFINITY, 5) < e(f64::NAN, 0));
+        assert_eq!(e(-0.0, 3), e(0.0, 3));
+        assert_eq!(e(f64::NAN, 3), e(f64::NAN, 3));
+    }
+
+    fn paths_with<H: PriorityQueue<f64>>(g: &AdjListGraph<f64>) -> Vec<Option<Vec<usize>>> {
+        let mut buf = dynamic(g.n());
+        Dijkstra::<f64, H>::new().run(g, 0, &mut buf);
+        (0..g.n()).map(|v| buf.path_to(v)).collect()
+    }
+
+    #[test]
+    fn test_equal_cost_paths_match_across_heaps() {
+        // Unit grid: many shortest paths to every cell
+        let g = grid::<f64>(12, 12, 1.0);
+        let expected = paths_with::<BinaryHeap<f64>>(&g);
+        assert_eq!(paths_with::<PairingHeap<f64>>(&g), expected);
+        assert_eq!(paths_with::<FibonacciHeap<f64>>(&g), expected);
+        assert_eq!(paths_with::<IndexedBinaryHeap<f64>>(&g), expected);
+        assert_eq!(paths_with::<DaryHeap<f64, 4>>(&g), expected);
+        assert_eq!(paths_with::<BinaryHeap<f64>>(&g), expected);
+    }
+}
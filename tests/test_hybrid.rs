@@ -0,0 +1,119 @@
+mod common;
+
+use common::{assertions::EPS_F64 as EPS, *};
+use sssp_fast::{
+    cheeky_hybrid, hybrid_to, AdjListGraph, Graph, Hybrid, HybridConfig, PairingHeap, SsspAlgorithm,
+};
+
+/// Check distances match Dijkstra for every vertex.
+fn matches_dijkstra(g: &AdjListGraph<f64>, source: usize, config: HybridConfig) {
+    let n = g.n();
+
+    let mut expected = dynamic(n);
+    cheeky_dijkstra(g, source, &mut expected);
+
+    let mut buf = dynamic(n);
+    let result = Hybrid::<f64>::with_config(config).run(g, source, &mut buf);
+
+    for v in 0..n {
+        if expected.dist[v].is_infinite() {
+            unreachable(&buf, v);
+        } else {
+            dist_eq(&buf, v, expected.dist[v], EPS);
+            let path = buf.path_to(v).unwrap();
+            assert_eq!(path[0], source);
+            assert_eq!(*path.last().unwrap(), v);
+        }
+    }
+    assert_eq!(
+        result.vertices_reached,
+        (0..n).filter(|&v| !expected.dist[v].is_infinite()).count()
+    );
+}
+
+#[test]
+fn test_linear_chain() {
+    let g = linear(5, 1.0);
+    let mut buf = dynamic(5);
+    cheeky_hybrid(&g, 0, &mut buf);
+
+    for i in 0..5 {
+        dist_eq(&buf, i, i as f64, EPS);
+    }
+    path_eq(&buf, 4, &[0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_diamond_selects_shortest() {
+    let (g, optimal) = diamond(1.0, 1.0, 3.0, 10.0, 1.0);
+    let mut buf = dynamic(4);
+    cheeky_hybrid(&g, 0, &mut buf);
+
+    dist_eq(&buf, 3, optimal, EPS);
+}
+
+#[test]
+fn test_disconnected_graph() {
+    let g = disconnected(6, 1.0);
+    let mut buf = dynamic(6);
+    cheeky_hybrid(&g, 0, &mut buf);
+
+    dists_eq(&buf, &[(0, 0.0), (1, 1.0), (2, 2.0)], EPS);
+    all_unreachable(&buf, &[3, 4, 5]);
+}
+
+#[test]
+fn test_grid_ties() {
+    // Unit weights: every level sees large tie classes
+    let g = grid(12, 12, 1.0);
+    matches_dijkstra(&g, 0, HybridConfig::default());
+    matches_dijkstra(&g, 0, HybridConfig::default().with_params(1, 1));
+    matches_dijkstra(&g, 0, HybridConfig::default().with_params(2, 1));
+}
+
+#[test]
+fn test_random_graphs() {
+    for seed in 1..20 {
+        let g = random::<f64>(200, 800, 100, seed);
+        matches_dijkstra(&g, 0, HybridConfig::default());
+        matches_dijkstra(&g, 0, HybridConfig::default().with_params(2, 2));
+        matches_dijkstra(&g, 0, HybridConfig::default().with_params(3, 1));
+    }
+}
+
+#[test]
+fn test_zero_weights() {
+    for seed in 1..10 {
+        let g = random::<f64>(100, 400, 2, seed);
+        matches_dijkstra(&g, 0, HybridConfig::default().with_params(1, 1));
+        matches_dijkstra(&g, 0, HybridConfig::default().with_params(2, 2));
+    }
+}
+
+#[test]
+fn test_early_stop() {
+    let g = linear(100, 1.0);
+    let mut buf = dynamic(100);
+    let result = hybrid_to(&g, 0, 10, &mut buf);
+
+    dist_eq(&buf, 10, 10.0, EPS);
+    path_eq(&buf, 10, &(0..=10).collect::<Vec<_>>());
+    assert!(result.iterations < 100);
+}
+
+#[test]
+fn test_reusable_instance() {
+    let g = grid(6, 6, 1.0);
+    let mut algo: Hybrid<f64, PairingHeap<f64>> = Hybrid::new();
+
+    for source in [0, 7, 35] {
+        let mut buf = dynamic(36);
+        algo.run(&g, source, &mut buf);
+        dist_eq(&buf, source, 0.0, EPS);
+    }
+}
+
+#[test]
+fn test_algo_info() {
+    check_algo_info(&Hybrid::<f64>::new(), "Hybrid", false);
+}
@@ -0,0 +1,151 @@
+mod common;
+
+use common::{assertions::EPS_F64 as EPS, *};
+use sssp_fast::{
+    cheeky_johnson, AdjListGraph, ApspAlgorithm, ApspAlgorithmInfo, Graph, Johnson, JohnsonConfig,
+};
+
+/// Check every pair against Floyd-Warshall.
+fn matches_floyd_warshall(g: &AdjListGraph<f64>) {
+    let n = g.n();
+    let mut expected = apsp(n);
+    cheeky_floyd_warshall(g, &mut expected);
+
+    for config in [
+        JohnsonConfig::default(),
+        JohnsonConfig::default().sequential(),
+    ] {
+        let mut buf = apsp(n);
+        let result = Johnson::<f64>::with_config(config).run(g, &mut buf);
+        assert!(!result.negative_cycle);
+
+        for i in 0..n {
+            for j in 0..n {
+                let d = expected.get(i, j);
+                if d.is_infinite() {
+                    apsp_unreachable(&buf, i, j);
+                    assert!(buf.path(i, j).is_none());
+                } else {
+                    apsp_dist_eq(&buf, i, j, d, EPS);
+
+                    // Path must be a real walk with the same cost
+                    let path = buf.path(i, j).unwrap();
+                    assert_eq!((path[0], *path.last().unwrap()), (i, j));
+                    let mut cost = 0.0;
+                    for hop in path.windows(2) {
+                        let mut best = f64::INFINITY;
+                        g.for_each_out_edge(hop[0], |v, w| {
+                            if v == hop[1] && w < best {
+                                best = w;
+                            }
+                        });
+                        cost += best;
+                    }
+                    approx_eq(cost, d, 1e-9);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_shortest_paths() {
+    let (g, _) = diamond(1.0, 1.0, 3.0, 10.0, 1.0);
+    let mut buf = apsp(4);
+    cheeky_johnson(&g, &mut buf);
+
+    apsp_dist_eq(&buf, 0, 0, 0.0, EPS);
+    apsp_dist_eq(&buf, 0, 1, 1.0, EPS);
+    apsp_dist_eq(&buf, 0, 2, 2.0, EPS);
+    apsp_dist_eq(&buf, 0, 3, 3.0, EPS);
+    apsp_path_eq(&buf, 0, 3, &[0, 1, 2, 3]);
+}
+
+#[test]
+fn test_unreachable_pairs() {
+    let g = disconnected(6, 1.0);
+    let mut buf = apsp(6);
+    cheeky_johnson(&g, &mut buf);
+
+    apsp_dist_eq(&buf, 0, 1, 1.0, EPS);
+    apsp_unreachable(&buf, 0, 3);
+    apsp_unreachable(&buf, 2, 4);
+}
+
+#[test]
+fn test_negative_weights() {
+    let mut g: AdjListGraph<f64> = AdjListGraph::new(4);
+    g.add_edge(0, 1, 5.0);
+    g.add_edge(1, 3, 5.0);
+    g.add_edge(0, 2, 10.0);
+    g.add_edge(2, 3, -5.0);
+    g.add_edge(3, 0, -2.0);
+
+    let mut buf = apsp(4);
+    let result = cheeky_johnson(&g, &mut buf);
+
+    assert!(!result.negative_cycle);
+    apsp_dist_eq(&buf, 0, 3, 5.0, EPS);
+    apsp_path_eq(&buf, 0, 3, &[0, 2, 3]);
+    apsp_dist_eq(&buf, 2, 1, -2.0, EPS);
+    apsp_path_eq(&buf, 2, 1, &[2, 3, 0, 1]);
+    matches_floyd_warshall(&g);
+}
+
+#[test]
+fn test_negative_cycle() {
+    let mut g: AdjListGraph<f64> = AdjListGraph::new(3);
+    g.add_edge(0, 1, 1.0);
+    g.add_edge(1, 2, 1.0);
+    g.add_edge(2, 0, -3.0);
+
+    let mut buf = apsp(3);
+    let result = cheeky_johnson(&g, &mut buf);
+
+    assert!(result.negative_cycle);
+}
+
+#[test]
+fn test_matches_floyd_warshall() {
+    matches_floyd_warshall(&grid(4, 4, 1.0));
+    matches_floyd_warshall(&complete(8, 2.0));
+    matches_floyd_warshall(&disconnected_undirected(8, 1.5));
+
+    // Random graphs with negative edges on a DAG (no cycles)
+    for seed in 1..6 {
+        let r = random::<f64>(30, 120, 20, seed);
+        let mut g: AdjListGraph<f64> = AdjListGraph::new(30);
+        for u in 0..30 {
+            for e in r.neighbors(u) {
+                let (a, b) = (u.min(e.to), u.max(e.to));
+                if a != b {
+                    g.add_edge(a, b, e.w - 10.0);
+                }
+            }
+        }
+        matches_floyd_warshall(&g);
+    }
+}
+
+#[test]
+fn test_algo_info() {
+    let algo = Johnson::<f64>::new();
+    assert_eq!(algo.name(), "Johnson");
+    assert!(algo.supports_negative_weights());
+}
+
+#[test]
+fn test_negative_cycle_extracted() {
+    let mut g: AdjListGraph<f64> = AdjListGraph::new(4);
+    g.add_edge(0, 1, 1.0);
+    g.add_edge(2, 3, 1.0);
+    g.add_edge(3, 2, -4.0);
+
+    let mut buf = apsp(4);
+    let result = cheeky_johnson(&g, &mut buf);
+
+    // Found from the virtual source even though vertex 0 cannot reach it
+    let cycle = result.cycle.expect("cycle not extracted");
+    negative_cycle_valid(&g, &cycle, EPS);
+    approx_eq(cycle.weight, -3.0, EPS);
+}
@@ -0,0 +1,190 @@
+mod common;
+
+use common::assertions::EPS_F64 as EPS;
+use common::*;
+use sssp_fast::{
+    cheeky_kruskal, cheeky_prim, AdjListGraph, Const, DisjointSet, Dyn, Graph, Kruskal,
+    KruskalConfig, MstAlgorithm, MstBuffers, MST_PARENT_NONE,
+};
+
+/// Parent pointers form a forest: roots have key 0, every edge exists in the graph.
+fn forest_valid(g: &AdjListGraph<f64>, buf: &MstBuffers<f64, Dyn>) {
+    for v in 0..g.n() {
+        assert!(buf.in_mst[v], "Vertex {v} not covered");
+        let p = buf.parent[v];
+        if p == MST_PARENT_NONE {
+            approx_eq(buf.key[v], 0.0, EPS);
+            continue;
+        }
+        let mut found = false;
+        g.for_each_out_edge(p, |to, w| found |= to == v && w == buf.key[v]);
+        assert!(found, "Edge {p} -> {v} not in graph");
+    }
+}
+
+#[test]
+fn test_classic_mst() {
+    let g = classic_mst_graph();
+    let mut buf = mst_dynamic(4);
+    let result = cheeky_kruskal(&g, 0, &mut buf);
+
+    // MST: 1-3 (1.0), 1-2 (2.0), 0-1 (4.0) = 7.0
+    approx_eq(result.total_weight, 7.0, EPS);
+    assert!(result.is_connected);
+    assert_eq!(buf.collect_edges().len(), 3);
+    assert_eq!(buf.parent_of(0), None);
+    forest_valid(&g, &buf);
+}
+
+#[test]
+fn test_disconnected_forest() {
+    let g = disconnected_undirected(6, 1.0);
+    let mut buf = mst_dynamic(6);
+    let result = cheeky_kruskal(&g, 4, &mut buf);
+
+    // Both components spanned, unlike Prim
+    assert_eq!(result.vertices_in_mst, 6);
+    approx_eq(result.total_weight, 4.0, EPS);
+    assert!(!result.is_connected);
+    assert_eq!(buf.collect_edges().len(), 4);
+
+    // Source roots its own tree, other tree rooted at its smallest vertex
+    assert_eq!(buf.parent_of(4), None);
+    assert_eq!(buf.parent_of(0), None);
+    forest_valid(&g, &buf);
+}
+
+#[test]
+fn test_isolated_vertices() {
+    let g: AdjListGraph<f64> = AdjListGraph::new(3);
+    let mut buf = mst_dynamic(3);
+    let result = cheeky_kruskal(&g, 1, &mut buf);
+
+    assert_eq!(result.vertices_in_mst, 3);
+    approx_eq(result.total_weight, 0.0, EPS);
+    assert!(!result.is_connected);
+    assert!(buf.collect_edges().is_empty());
+}
+
+#[test]
+fn test_parallel_edges_and_self_loops() {
+    let mut g: AdjListGraph<f64> = AdjListGraph::new(2);
+    add_undirected_edge(&mut g, 0, 1, 5.0);
+    add_undirected_edge(&mut g, 0, 1, 3.0);
+    add_undirected_edge(&mut g, 0, 1, 7.0);
+    g.add_edge(0, 0, 0.5);
+
+    let mut buf = mst_dynamic(2);
+    let result = cheeky_kruskal(&g, 0, &mut buf);
+
+    approx_eq(result.total_weight, 3.0, EPS);
+    assert!(result.is_connected);
+}
+
+#[test]
+fn test_grid() {
+    let g = grid_undirected(5, 5, 1.0);
+    let mut buf = mst_dynamic(25);
+    let result = cheeky_kruskal(&g, 0, &mut buf);
+
+    approx_eq(result.total_weight, 24.0, EPS);
+    assert!(result.is_connected);
+    forest_valid(&g, &buf);
+}
+
+#[test]
+fn test_matches_prim() {
+    for seed in 1..20 {
+        let g = random_undirected::<f64>(40, 120, 50, seed);
+
+        let mut kb = mst_dynamic(40);
+        let k = cheeky_kruskal(&g, 0, &mut kb);
+        forest_valid(&g, &kb);
+
+        let mut pb = mst_dynamic(40);
+        let p = cheeky_prim(&g, 0, &mut pb);
+
+        assert_eq!(k.is_connected, p.is_connected);
+        if p.is_connected {
+            approx_eq(k.total_weight, p.total_weight, EPS);
+        } else {
+            // Prim's tree is Kruskal's tree for that component
+            assert!(k.total_weight >= p.total_weight);
+        }
+    }
+}
+
+#[test]
+fn test_component_weights_match_prim() {
+    let g = disconnected_undirected(8, 2.0);
+    let mut kb = mst_dynamic(8);
+    let k = cheeky_kruskal(&g, 0, &mut kb);
+
+    let mut total = 0.0;
+    for root in [0, 4] {
+        let mut pb = mst_dynamic(8);
+        total += cheeky_prim(&g, root, &mut pb).total_weight;
+    }
+    approx_eq(k.total_weight, total, EPS);
+}
+
+#[test]
+fn test_without_early_exit() {
+    let g = random_undirected::<f64>(30, 200, 20, 7);
+    let mut a = mst_dynamic(30);
+    let mut b = mst_dynamic(30);
+
+    let fast = cheeky_kruskal(&g, 0, &mut a);
+    let full =
+        Kruskal::with_config(KruskalConfig::default().without_early_exit()).run(&g, 0, &mut b);
+
+    approx_eq(fast.total_weight, full.total_weight, EPS);
+    assert!(fast.iterations <= full.iterations);
+    assert_eq!(a.parent, b.parent);
+}
+
+#[test]
+fn test_reuse_across_graphs() {
+    let mut kruskal = Kruskal::<f64>::new();
+
+    let g = classic_mst_graph();
+    let mut buf = mst_dynamic(4);
+    approx_eq(kruskal.run(&g, 0, &mut buf).total_weight, 7.0, EPS);
+
+    let g = grid_undirected(3, 3, 2.0);
+    let mut buf = mst_dynamic(9);
+    approx_eq(kruskal.run(&g, 0, &mut buf).total_weight, 16.0, EPS);
+
+    let g = classic_mst_graph();
+    let mut buf = mst_dynamic(4);
+    approx_eq(kruskal.run(&g, 2, &mut buf).total_weight, 7.0, EPS);
+}
+
+#[test]
+fn test_static_dims() {
+    let g = classic_mst_graph();
+    let mut buf: MstBuffers<f64, Const<4>> = MstBuffers::new_inf(Const::<4>);
+    let result = cheeky_kruskal(&g, 0, &mut buf);
+
+    approx_eq(result.total_weight, 7.0, EPS);
+}
+
+#[test]
+fn test_disjoint_set() {
+    let mut sets = DisjointSet::new(6);
+    assert_eq!(sets.sets(), 6);
+
+    assert!(sets.union(0, 1));
+    assert!(sets.union(2, 3));
+    assert!(sets.union(1, 3));
+    assert!(!sets.union(0, 2));
+
+    assert!(sets.same(0, 3));
+    assert!(!sets.same(0, 4));
+    assert_eq!(sets.sets(), 3);
+
+    sets.reset(2);
+    assert_eq!(sets.len(), 2);
+    assert_eq!(sets.sets(), 2);
+    assert!(!sets.same(0, 1));
+}
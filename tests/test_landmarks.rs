@@ -0,0 +1,139 @@
+mod common;
+
+use common::{assertions::EPS_F64 as EPS, *};
+use sssp_fast::{
+    astar_with, AStar, AdjListGraph, Graph, Heuristic, LandmarkConfig, LandmarkHeuristic,
+    LandmarkSelection, SsspAlgorithm, ZeroHeuristic,
+};
+
+fn configs(count: usize) -> Vec<LandmarkConfig> {
+    vec![
+        LandmarkConfig::new(count, LandmarkSelection::Farthest),
+        LandmarkConfig::new(count, LandmarkSelection::Avoid),
+    ]
+}
+
+/// Never overestimates, and never drops by more than an edge weight.
+fn admissible_and_consistent(g: &AdjListGraph<f64>, h: &LandmarkHeuristic<f64>) {
+    for t in 0..g.n() {
+        let mut to_t = dynamic(g.n());
+        let reverse = {
+            let mut r: AdjListGraph<f64> = AdjListGraph::new(g.n());
+            for u in 0..g.n() {
+                g.for_each_out_edge(u, |v, w| r.add_edge(v, u, w));
+            }
+            r
+        };
+        cheeky_dijkstra(&reverse, t, &mut to_t);
+
+        for u in 0..g.n() {
+            let h_u = h.estimate(u, t);
+            assert!(h_u >= 0.0);
+            assert!(h_u <= to_t.dist[u] + EPS, "h({}, {}) overestimates", u, t);
+            g.for_each_out_edge(u, |v, w| {
+                assert!(
+                    h_u <= w + h.estimate(v, t) + EPS,
+                    "Inconsistent on ({}, {})",
+                    u,
+                    v
+                );
+            });
+        }
+    }
+}
+
+#[test]
+fn test_selects_distinct_landmarks() {
+    let g = grid_undirected(6, 6, 1.0);
+    for config in configs(4) {
+        let h = LandmarkHeuristic::with_config(&g, &config);
+        let mut l = h.landmarks().to_vec();
+        l.sort_unstable();
+        l.dedup();
+        assert_eq!(l.len(), 4);
+    }
+}
+
+#[test]
+fn test_farthest_picks_corners() {
+    let g = grid_undirected(5, 5, 1.0);
+    let h = LandmarkHeuristic::new(&g, 2);
+
+    // Second landmark is the corner opposite the first
+    let (a, b) = (h.landmarks()[0], h.landmarks()[1]);
+    assert!([0, 4, 20, 24].contains(&a));
+    assert_eq!(a + b, 24);
+}
+
+#[test]
+fn test_count_capped_by_vertices() {
+    let g = linear_undirected(3, 1.0);
+    for config in configs(10) {
+        let h = LandmarkHeuristic::with_config(&g, &config);
+        assert_eq!(h.landmarks().len(), 3);
+    }
+}
+
+#[test]
+fn test_admissible_random_graphs() {
+    for seed in 1..6 {
+        let g = random::<f64>(30, 120, 10, seed);
+        for config in configs(3) {
+            admissible_and_consistent(&g, &LandmarkHeuristic::with_config(&g, &config));
+        }
+    }
+}
+
+#[test]
+fn test_admissible_disconnected() {
+    let g = disconnected(6, 1.0);
+    for config in configs(2) {
+        admissible_and_consistent(&g, &LandmarkHeuristic::with_config(&g, &config));
+    }
+}
+
+#[test]
+fn test_exact_on_line_to_landmark() {
+    let g = linear_undirected(10, 1.0);
+    let h = LandmarkHeuristic::from_landmarks(&g, vec![9]);
+
+    for v in 0..10 {
+        approx_eq(h.estimate(v, 9), (9 - v) as f64, EPS);
+    }
+}
+
+#[test]
+fn test_astar_matches_dijkstra() {
+    let g = random::<f64>(80, 400, 20, 3);
+    let h = LandmarkHeuristic::new(&g, 4);
+
+    let mut expected = dynamic(g.n());
+    cheeky_dijkstra(&g, 0, &mut expected);
+
+    for target in 1..g.n() {
+        let mut buf = dynamic(g.n());
+        astar_with(&g, 0, target, &h, &mut buf);
+        if expected.dist[target].is_infinite() {
+            unreachable(&buf, target);
+        } else {
+            dist_eq(&buf, target, expected.dist[target], EPS);
+            path_valid(&g, &buf, 0, target, EPS);
+        }
+    }
+}
+
+#[test]
+fn test_fewer_iterations_than_zero_heuristic() {
+    let g = grid_undirected(20, 20, 1.0);
+    let h = LandmarkHeuristic::new(&g, 4);
+    let (source, target) = (0, 20 * 10 + 19);
+
+    let mut buf = dynamic(g.n());
+    let alt = AStar::<f64, &LandmarkHeuristic<f64>>::new(target, &h).run(&g, source, &mut buf);
+    dist_eq(&buf, target, 29.0, EPS);
+
+    let mut buf = dynamic(g.n());
+    let plain = AStar::<f64, ZeroHeuristic>::new(target, ZeroHeuristic).run(&g, source, &mut buf);
+
+    assert!(alt.iterations < plain.iterations);
+}
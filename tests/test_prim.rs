@@ -4,16 +4,6 @@ use common::assertions::EPS_F64 as EPS;
 use common::*;
 use sssp_fast::{cheeky_prim, AdjListGraph, Const, MstAlgorithm, MstBuffers, Prim};
 
-fn classic_mst_graph() -> AdjListGraph<f64> {
-    let mut g = AdjListGraph::new(4);
-    add_undirected_edge(&mut g, 0, 1, 4.0);
-    add_undirected_edge(&mut g, 0, 3, 5.0);
-    add_undirected_edge(&mut g, 1, 2, 2.0);
-    add_undirected_edge(&mut g, 1, 3, 1.0);
-    add_undirected_edge(&mut g, 2, 3, 3.0);
-    g
-}
-
 #[test]
 fn test_classic_mst() {
     let g = classic_mst_graph();
@@ -109,3 +99,49 @@ fn test_static_dims() {
 
     approx_eq(result.total_weight, 7.0, EPS);
 }
+
+#[test]
+fn test_with_indexed_heap() {
+    use sssp_fast::{IndexedBinaryHeap, PrimConfig};
+
+    let g = random_undirected::<f64>(200, 1000, 50, 4);
+    let mut expected = mst_dynamic(200);
+    let lazy = cheeky_prim(&g, 0, &mut expected);
+
+    for config in [
+        PrimConfig::default(),
+        PrimConfig::default().without_lazy_deletion(),
+    ] {
+        let mut buf = mst_dynamic(200);
+        let result = Prim::<f64, IndexedBinaryHeap<f64>>::with_config(config).run(&g, 0, &mut buf);
+        approx_eq(result.total_weight, lazy.total_weight, EPS);
+        assert_eq!(result.iterations, lazy.iterations);
+    }
+}
+
+#[test]
+fn test_fibonacci_heap_dense() {
+    use sssp_fast::FibonacciHeap;
+
+    let g = random_undirected::<f64>(100, 3000, 1000, 8);
+    let mut expected = mst_dynamic(100);
+    let lazy = cheeky_prim(&g, 0, &mut expected);
+
+    let mut buf = mst_dynamic(100);
+    let result = Prim::<f64, FibonacciHeap<f64>>::new().run(&g, 0, &mut buf);
+    approx_eq(result.total_weight, lazy.total_weight, EPS);
+    assert_eq!(result.vertices_in_mst, 100);
+}
+
+#[test]
+fn test_with_dary_heap() {
+    use sssp_fast::DaryHeap;
+
+    let g = random_undirected::<f64>(200, 1000, 50, 5);
+    let mut expected = mst_dynamic(200);
+    let reference = cheeky_prim(&g, 0, &mut expected);
+
+    let mut buf = mst_dynamic(200);
+    let result = Prim::<f64, DaryHeap<f64, 4>>::new().run(&g, 0, &mut buf);
+    approx_eq(result.total_weight, reference.total_weight, EPS);
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use common::assertions::EPS_F64 as EPS;
+use common::*;
+use sssp_fast::{
+    cheeky_dijkstra, AdjListGraph, BidirectionalDijkstra, BidirectionalGraph, CsrGraph, Graph,
+    ReverseGraph, WithInEdges,
+};
+
+/// All (u, v, w) edges, sorted, for order-independent comparison.
+fn edge_set<G: Graph<f64>>(g: &G) -> Vec<(usize, usize, u64)> {
+    let mut out = Vec::new();
+    for u in 0..g.n() {
+        g.for_each_out_edge(u, |v, w| out.push((u, v, w.to_bits())));
+    }
+    out.sort_unstable();
+    out
+}
+
+fn flipped(edges: &[(usize, usize, u64)]) -> Vec<(usize, usize, u64)> {
+    let mut out: Vec<_> = edges.iter().map(|&(u, v, w)| (v, u, w)).collect();
+    out.sort_unstable();
+    out
+}
+
+#[test]
+fn test_adj_list_transpose() {
+    let g = random::<f64>(20, 80, 9, 4);
+    let t = g.transpose();
+
+    assert_eq!(t.n(), g.n());
+    assert_eq!(t.m(), g.m());
+    assert_eq!(edge_set(&t), flipped(&edge_set(&g)));
+    assert_eq!(edge_set(&t.transpose()), edge_set(&g));
+}
+
+#[test]
+fn test_csr_transpose() {
+    let g = random::<f64>(20, 80, 9, 5);
+    let csr = CsrGraph::from(&g);
+    let t = csr.transpose();
+
+    assert_eq!(edge_set(&t), edge_set(&g.transpose()));
+    // In-edges come out grouped by source
+    for v in 0..t.n() {
+        let (sources, _) = t.neighbors(v);
+        assert!(sources.windows(2).all(|p| p[0] <= p[1]));
+    }
+}
+
+#[test]
+fn test_in_edges() {
+    let mut g: AdjListGraph<f64> = AdjListGraph::new(4);
+    g.add_edge(0, 2, 1.0);
+    g.add_edge(1, 2, 2.0);
+    g.add_edge(3, 2, 3.0);
+    g.add_edge(2, 0, 4.0);
+    let g = WithInEdges::new(g);
+
+    let mut into_2 = Vec::new();
+    g.for_each_in_edge(2, |u, w| into_2.push((u, w)));
+    assert_eq!(into_2, vec![(0, 1.0), (1, 2.0), (3, 3.0)]);
+
+    let mut into_1 = Vec::new();
+    g.for_each_in_edge(1, |u, w| into_1.push((u, w)));
+    assert!(into_1.is_empty());
+}
+
+#[test]
+fn test_reverse_view() {
+    let g = WithInEdges::new(random::<f64>(25, 100, 9, 6));
+    let view = ReverseGraph::new(&g);
+
+    assert_eq!(view.n(), g.n());
+    assert_eq!(edge_set(&view), edge_set(g.reverse()));
+    assert_eq!(edge_set(&ReverseGraph::new(&view)), edge_set(g.graph()));
+}
+
+#[test]
+fn test_distances_to_target() {
+    // Dijkstra on the reverse from t gives d(v, t) for every v
+    let g = WithInEdges::new(random::<f64>(30, 150, 20, 8));
+    let target = 7;
+    let mut to_target = dynamic(30);
+    cheeky_dijkstra(&ReverseGraph::new(&g), target, &mut to_target);
+
+    for v in 0..30 {
+        let mut from_v = dynamic(30);
+        cheeky_dijkstra(g.graph(), v, &mut from_v);
+        assert_eq!(to_target.dist[v], from_v.dist[target]);
+    }
+}
+
+#[test]
+fn test_bidirectional_with_in_edges() {
+    let g = WithInEdges::new(grid_undirected(8, 8, 1.0));
+    let mut buf = dynamic(64);
+    BidirectionalDijkstra::<f64>::new(63).run_with_in_edges(&g, 0, &mut buf);
+
+    approx_eq(buf.dist[63], 14.0, EPS);
+    path_valid(g.graph(), &buf, 0, 63, EPS);
+}
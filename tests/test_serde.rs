@@ -0,0 +1,159 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+use common::*;
+use serde::{de::DeserializeOwned, Serialize};
+use sssp_fast::{
+    cheeky_bellman_ford, cheeky_dijkstra, cheeky_floyd_warshall, cheeky_prim, AdjListGraph,
+    ApspBuffers, Const, Dyn, Edge, Graph, MstBuffers, SsspBuffers,
+};
+
+fn round_trip<V: Serialize + DeserializeOwned>(value: &V) -> V {
+    let json = serde_json::to_string(value).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+fn edges_of<G: Graph<f64>>(g: &G) -> Vec<(usize, usize, f64)> {
+    let mut out = Vec::new();
+    for u in 0..g.n() {
+        g.for_each_out_edge(u, |v, w| out.push((u, v, w)));
+    }
+    out
+}
+
+#[test]
+fn test_edge_json() {
+    let json = serde_json::to_string(&Edge::new(3, 1.5)).unwrap();
+    assert_eq!(json, r#"{"to":3,"w":1.5}"#);
+    assert_eq!(round_trip(&Edge::new(3, 1.5f32)), Edge::new(3, 1.5f32));
+}
+
+#[test]
+fn test_graph_round_trip() {
+    let g = random::<f64>(40, 200, 50, 7);
+    let back = round_trip(&g);
+    assert_eq!(back.n(), g.n());
+    assert_eq!(edges_of(&back), edges_of(&g));
+}
+
+#[test]
+fn test_graph_rejects_bad_edges() {
+    let json = r#"{"n":2,"adj":[[{"to":5,"w":1.0}],[]]}"#;
+    assert!(serde_json::from_str::<AdjListGraph<f64>>(json).is_err());
+
+    let json = r#"{"n":3,"adj":[[],[]]}"#;
+    assert!(serde_json::from_str::<AdjListGraph<f64>>(json).is_err());
+}
+
+#[test]
+fn test_infinity_is_json_safe() {
+    let g = disconnected::<f64>(4, 1.0);
+    let mut buf = dynamic(4);
+    cheeky_dijkstra(&g, 0, &mut buf);
+
+    let json = serde_json::to_string(&buf).unwrap();
+    assert!(json.contains(r#""inf""#));
+
+    let back: SsspBuffers<f64, Dyn> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.dist, buf.dist);
+    assert_eq!(back.parent, buf.parent);
+    assert_eq!(back.path_to(1), buf.path_to(1));
+    unreachable(&back, 3);
+}
+
+#[test]
+fn test_non_finite_strings() {
+    let dist: SsspBuffers<f64, Dyn> =
+        serde_json::from_str(r#"{"dist":["-inf",2,"nan"],"parent":[0,1,2]}"#).unwrap();
+    assert_eq!(dist.dist[0], f64::NEG_INFINITY);
+    assert_eq!(dist.dist[1], 2.0);
+    assert!(dist.dist[2].is_nan());
+
+    let bad = r#"{"dist":["infinity"],"parent":[0]}"#;
+    assert!(serde_json::from_str::<SsspBuffers<f64, Dyn>>(bad).is_err());
+}
+
+#[test]
+fn test_static_buffers_check_length() {
+    let mut buf = fixed::<f64, 4>();
+    cheeky_dijkstra(&linear::<f64>(4, 2.0), 0, &mut buf);
+    let back = round_trip(&buf);
+    assert_eq!(back.dist, buf.dist);
+
+    let short = r#"{"dist":[0,1,2],"parent":[0,0,1]}"#;
+    assert!(serde_json::from_str::<SsspBuffers<f64, Const<4>>>(short).is_err());
+}
+
+#[test]
+fn test_sssp_result_round_trip() {
+    let g = random::<f64>(50, 300, 20, 1);
+    let mut buf = dynamic(50);
+    let result = cheeky_dijkstra(&g, 0, &mut buf);
+
+    let back = round_trip(&result);
+    assert_eq!(back.iterations, result.iterations);
+    assert_eq!(back.vertices_reached, result.vertices_reached);
+    assert_eq!(back.total_distance, result.total_distance);
+    assert!(back.cycle.is_none());
+}
+
+#[test]
+fn test_negative_cycle_round_trip() {
+    let mut g = AdjListGraph::new(3);
+    g.add_edge(0, 1, 1.0);
+    g.add_edge(1, 2, -3.0);
+    g.add_edge(2, 1, 1.0);
+    let mut buf = dynamic(3);
+    let result = cheeky_bellman_ford(&g, 0, &mut buf);
+    assert!(result.negative_cycle);
+
+    let back = round_trip(&result);
+    let (cycle, original) = (back.cycle.unwrap(), result.cycle.unwrap());
+    assert_eq!(cycle.vertices, original.vertices);
+    assert_eq!(cycle.weight, original.weight);
+}
+
+#[test]
+fn test_mst_round_trip() {
+    let g = disconnected_undirected::<f64>(6, 2.0);
+    let mut buf: MstBuffers<f64, Dyn> = mst_dynamic(6);
+    let result = cheeky_prim(&g, 0, &mut buf);
+
+    let back = round_trip(&buf);
+    assert_eq!(back.key, buf.key);
+    assert_eq!(back.parent, buf.parent);
+    assert_eq!(back.in_mst, buf.in_mst);
+    assert_eq!(back.collect_edges(), buf.collect_edges());
+
+    let result_back = round_trip(&result);
+    assert_eq!(result_back.total_weight, result.total_weight);
+    assert_eq!(result_back.is_connected, result.is_connected);
+}
+
+#[test]
+fn test_apsp_round_trip() {
+    let g = disconnected::<f64>(5, 1.5);
+    let mut buf = apsp(5);
+    let result = cheeky_floyd_warshall(&g, &mut buf);
+
+    let back = round_trip(&buf);
+    assert_eq!(back.n, 5);
+    assert_eq!(back.dist, buf.dist);
+    assert_eq!(back.path(0, 2), buf.path(0, 2));
+    apsp_unreachable(&back, 0, 4);
+
+    let result_back = round_trip(&result);
+    assert_eq!(result_back.pairs_reached, result.pairs_reached);
+
+    let bad = r#"{"n":2,"dist":[0,1,1],"next":[0,1,0,1]}"#;
+    assert!(serde_json::from_str::<ApspBuffers<f64>>(bad).is_err());
+}
+
+#[test]
+fn test_apsp_rejects_overflowing_n() {
+    // n * n wraps to 0 on 64-bit, which would match the empty matrices
+    let bad = r#"{"n":4294967296,"dist":[],"next":[]}"#;
+    let err = serde_json::from_str::<ApspBuffers<f64>>(bad).unwrap_err();
+    assert!(err.to_string().contains("overflows"), "{err}");
+}
@@ -0,0 +1,136 @@
+mod common;
+
+use common::{assertions::EPS_F64 as EPS, *};
+use sssp_fast::{cheeky_spfa, AdjListGraph, Graph, Spfa, SpfaConfig, SsspAlgorithm};
+
+fn configs() -> Vec<SpfaConfig> {
+    vec![
+        SpfaConfig::default(),
+        SpfaConfig::default().with_slf(),
+        SpfaConfig::default().with_lll(),
+        SpfaConfig::default().with_slf().with_lll(),
+    ]
+}
+
+#[test]
+fn test_linear_chain() {
+    let g = linear(5, 1.0);
+    let mut buf = dynamic(5);
+    cheeky_spfa(&g, 0, &mut buf);
+
+    for i in 0..5 {
+        dist_eq(&buf, i, i as f64, EPS);
+    }
+}
+
+#[test]
+fn test_disconnected_graph() {
+    let g = disconnected(6, 1.0);
+    let mut buf = dynamic(6);
+    cheeky_spfa(&g, 0, &mut buf);
+
+    dists_eq(&buf, &[(0, 0.0), (1, 1.0), (2, 2.0)], EPS);
+    all_unreachable(&buf, &[3, 4, 5]);
+}
+
+#[test]
+fn test_negative_weight_shorter_path() {
+    let mut g: AdjListGraph<f64> = AdjListGraph::new(4);
+    g.add_edge(0, 1, 5.0);
+    g.add_edge(1, 3, 5.0);
+    g.add_edge(0, 2, 10.0);
+    g.add_edge(2, 3, -5.0);
+
+    for config in configs() {
+        let mut buf = dynamic(4);
+        let result = Spfa::<f64>::with_config(config).run(&g, 0, &mut buf);
+
+        dist_eq(&buf, 3, 5.0, EPS);
+        path_eq(&buf, 3, &[0, 2, 3]);
+        assert!(!result.negative_cycle);
+    }
+}
+
+#[test]
+fn test_matches_bellman_ford() {
+    for seed in 1..10 {
+        // Negative edges on a DAG (no cycles)
+        let r = random::<f64>(100, 500, 20, seed);
+        let mut g: AdjListGraph<f64> = AdjListGraph::new(100);
+        for u in 0..100 {
+            for e in r.neighbors(u) {
+                if u < e.to {
+                    g.add_edge(u, e.to, e.w - 5.0);
+                }
+            }
+        }
+
+        let mut expected = dynamic(100);
+        cheeky_bellman_ford(&g, 0, &mut expected);
+
+        for config in configs() {
+            let mut buf = dynamic(100);
+            let result = Spfa::<f64>::with_config(config).run(&g, 0, &mut buf);
+            assert!(!result.negative_cycle);
+            for v in 0..g.n() {
+                assert_eq!(buf.dist[v], expected.dist[v]);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_fewer_scans_than_bellman_ford() {
+    let g = linear(50, 1.0);
+
+    let mut buf = dynamic(50);
+    let result = cheeky_spfa(&g, 0, &mut buf);
+
+    // Each vertex scanned once on a chain
+    assert_eq!(result.iterations, 50);
+}
+
+#[test]
+fn test_simple_negative_cycle() {
+    let mut g: AdjListGraph<f64> = AdjListGraph::new(3);
+    g.add_edge(0, 1, 1.0);
+    g.add_edge(1, 2, 1.0);
+    g.add_edge(2, 0, -3.0);
+
+    for config in configs() {
+        let mut buf = dynamic(3);
+        let result = Spfa::<f64>::with_config(config).run(&g, 0, &mut buf);
+        assert!(result.negative_cycle);
+    }
+}
+
+#[test]
+fn test_self_loop_negative() {
+    let mut g: AdjListGraph<f64> = AdjListGraph::new(2);
+    g.add_edge(0, 1, 1.0);
+    g.add_edge(1, 1, -1.0);
+
+    let mut buf = dynamic(2);
+    let result = cheeky_spfa(&g, 0, &mut buf);
+
+    assert!(result.negative_cycle);
+}
+
+#[test]
+fn test_unreachable_negative_cycle() {
+    let mut g: AdjListGraph<f64> = AdjListGraph::new(4);
+    g.add_edge(0, 1, 1.0);
+    g.add_edge(2, 3, 1.0);
+    g.add_edge(3, 2, -5.0);
+
+    let mut buf = dynamic(4);
+    let result = cheeky_spfa(&g, 0, &mut buf);
+
+    assert!(!result.negative_cycle);
+    dist_eq(&buf, 1, 1.0, EPS);
+}
+
+#[test]
+fn test_algo_info() {
+    check_algo_info(&Spfa::<f64>::new(), "SPFA", true);
+}
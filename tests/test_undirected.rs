@@ -0,0 +1,130 @@
+mod common;
+
+use common::assertions::EPS_F64 as EPS;
+use common::*;
+use sssp_fast::{
+    cheeky_boruvka, cheeky_dijkstra, cheeky_kruskal, cheeky_prim, AdjListGraph, BidirectionalGraph,
+    Graph, UndirectedGraph,
+};
+
+/// Same edges as `classic_mst_graph`, inserted once.
+fn classic() -> UndirectedGraph<f64> {
+    UndirectedGraph::from_edges(
+        4,
+        [
+            (0, 1, 4.0),
+            (0, 3, 5.0),
+            (1, 2, 2.0),
+            (1, 3, 1.0),
+            (2, 3, 3.0),
+        ],
+    )
+}
+
+/// Directed double-insertion of the same graph.
+fn doubled(g: &UndirectedGraph<f64>) -> AdjListGraph<f64> {
+    let mut d = AdjListGraph::new(g.n());
+    for e in g.edges() {
+        add_undirected_edge(&mut d, e.u, e.v, e.w);
+    }
+    d
+}
+
+#[test]
+fn test_both_directions() {
+    let mut g: UndirectedGraph<f64> = UndirectedGraph::new(3);
+    let a = g.add_edge(0, 1, 2.0);
+    let b = g.add_edge(2, 1, 5.0);
+
+    assert_eq!((a, b), (0, 1));
+    assert_eq!(g.m(), 2);
+    assert_eq!(out_edges(&g, 0), vec![(1, 2.0)]);
+    assert_eq!(out_edges(&g, 1), vec![(0, 2.0), (2, 5.0)]);
+    assert_eq!(out_edges(&g, 2), vec![(1, 5.0)]);
+    assert_eq!(g.incident(1), &[0, 1]);
+    assert_eq!(g.edge(b).other(2), 1);
+}
+
+#[test]
+fn test_set_weight_is_symmetric() {
+    let mut g = classic();
+    g.set_weight(3, 10.0); // 1 -- 3
+
+    assert!(out_edges(&g, 1).contains(&(3, 10.0)));
+    assert!(out_edges(&g, 3).contains(&(1, 10.0)));
+    assert_eq!(g.m(), 5);
+}
+
+#[test]
+fn test_self_loop_once() {
+    let mut g: UndirectedGraph<f64> = UndirectedGraph::new(2);
+    g.add_edge(0, 0, 1.0);
+    g.add_edge(0, 1, 2.0);
+
+    assert_eq!(g.degree(0), 2);
+    assert_eq!(out_edges(&g, 0), vec![(0, 1.0), (1, 2.0)]);
+}
+
+#[test]
+fn test_in_edges_mirror_out_edges() {
+    let g = classic();
+    for v in 0..g.n() {
+        let mut ins = Vec::new();
+        g.for_each_in_edge(v, |u, w| ins.push((u, w)));
+        assert_eq!(ins, out_edges(&g, v));
+    }
+}
+
+#[test]
+fn test_mst_algorithms() {
+    let g = classic();
+    let mut buf = mst_dynamic(4);
+
+    approx_eq(cheeky_prim(&g, 0, &mut buf).total_weight, 7.0, EPS);
+    approx_eq(cheeky_kruskal(&g, 0, &mut buf).total_weight, 7.0, EPS);
+    approx_eq(cheeky_boruvka(&g, 0, &mut buf).total_weight, 7.0, EPS);
+}
+
+#[test]
+fn test_matches_double_insertion() {
+    for seed in 1..10 {
+        let r = random::<f64>(40, 100, 30, seed);
+        let mut g = UndirectedGraph::new(40);
+        for u in 0..40 {
+            r.for_each_out_edge(u, |v, w| {
+                g.add_edge(u, v, w);
+            });
+        }
+        let d = doubled(&g);
+
+        let mut a = mst_dynamic(40);
+        let mut b = mst_dynamic(40);
+        let pa = cheeky_prim(&g, 0, &mut a);
+        let pb = cheeky_prim(&d, 0, &mut b);
+        approx_eq(pa.total_weight, pb.total_weight, EPS);
+        assert_eq!(pa.vertices_in_mst, pb.vertices_in_mst);
+
+        let mut sa = dynamic(40);
+        let mut sb = dynamic(40);
+        cheeky_dijkstra(&g, 0, &mut sa);
+        cheeky_dijkstra(&d, 0, &mut sb);
+        assert_eq!(sa.dist, sb.dist);
+    }
+}
+
+#[test]
+fn test_spanning_graph() {
+    let g = classic();
+    let mut buf = mst_dynamic(4);
+    cheeky_prim(&g, 0, &mut buf);
+
+    let tree = buf.spanning_graph();
+    assert_eq!(tree.n(), 4);
+    assert_eq!(tree.m(), 3);
+    let total: f64 = tree.edges().iter().map(|e| e.w).sum();
+    approx_eq(total, 7.0, EPS);
+
+    // A tree's MST is itself
+    let mut again = mst_dynamic(4);
+    approx_eq(cheeky_prim(&tree, 2, &mut again).total_weight, 7.0, EPS);
+}
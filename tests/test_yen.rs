@@ -0,0 +1,134 @@
+mod common;
+
+use common::{assertions::EPS_F64 as EPS, *};
+use sssp_fast::{k_shortest_paths, AdjListGraph, KPath};
+
+fn paths(found: &[KPath<f64>]) -> Vec<Vec<usize>> {
+    found.iter().map(|p| p.path.clone()).collect()
+}
+
+/// Nondecreasing costs, loopless, distinct, and costs match the edges.
+fn well_formed(g: &AdjListGraph<f64>, found: &[KPath<f64>], source: usize, target: usize) {
+    for pair in found.windows(2) {
+        assert!(pair[0].cost <= pair[1].cost, "Costs out of order");
+    }
+    let mut all = paths(found);
+    all.sort();
+    all.dedup();
+    assert_eq!(all.len(), found.len(), "Duplicate path");
+
+    for p in found {
+        assert_eq!(p.path.first(), Some(&source));
+        assert_eq!(p.path.last(), Some(&target));
+        let mut vertices = p.path.clone();
+        vertices.sort_unstable();
+        vertices.dedup();
+        assert_eq!(vertices.len(), p.path.len(), "Path has a loop");
+        approx_eq(path_weight(g, &p.path).unwrap(), p.cost, EPS);
+    }
+}
+
+#[test]
+fn test_diamond_all_paths() {
+    // 0->1->3 = 4, 0->1->2->3 = 3, 0->2->3 = 11
+    let (g, optimal) = diamond(1.0, 1.0, 3.0, 10.0, 1.0);
+    let found = k_shortest_paths(&g, 0, 3, 5);
+
+    assert_eq!(
+        paths(&found),
+        vec![vec![0, 1, 2, 3], vec![0, 1, 3], vec![0, 2, 3]]
+    );
+    approx_eq(found[0].cost, optimal, EPS);
+    approx_eq(found[1].cost, 4.0, EPS);
+    approx_eq(found[2].cost, 11.0, EPS);
+}
+
+#[test]
+fn test_diamond_k_limits_output() {
+    let (g, _) = diamond(1.0, 1.0, 3.0, 10.0, 1.0);
+    assert_eq!(k_shortest_paths(&g, 0, 3, 2).len(), 2);
+    assert!(k_shortest_paths(&g, 0, 3, 0).is_empty());
+}
+
+#[test]
+fn test_grid_monotone_paths() {
+    // Right/down moves only: C(4, 2) = 6 paths from corner to corner, all cost 4.
+    // Dijkstra picks the first; candidate ties then go by vertex sequence.
+    let g = grid(3, 3, 1.0);
+    let found = k_shortest_paths(&g, 0, 8, 10);
+
+    assert_eq!(
+        paths(&found),
+        vec![
+            vec![0, 1, 2, 5, 8],
+            vec![0, 1, 4, 5, 8],
+            vec![0, 1, 4, 7, 8],
+            vec![0, 3, 4, 5, 8],
+            vec![0, 3, 4, 7, 8],
+            vec![0, 3, 6, 7, 8],
+        ]
+    );
+    for p in &found {
+        approx_eq(p.cost, 4.0, EPS);
+    }
+}
+
+#[test]
+fn test_equal_cost_first_path_from_dijkstra() {
+    // Both paths cost 2; Dijkstra settles 2 before 1, so [0, 2, 3] comes first
+    // even though [0, 1, 3] is lexicographically smaller
+    let mut g = AdjListGraph::new(4);
+    g.add_edge(0, 1, 1.0);
+    g.add_edge(0, 2, 0.5);
+    g.add_edge(1, 3, 1.0);
+    g.add_edge(2, 3, 1.5);
+    let found = k_shortest_paths(&g, 0, 3, 2);
+
+    assert_eq!(paths(&found), vec![vec![0, 2, 3], vec![0, 1, 3]]);
+    approx_eq(found[0].cost, 2.0, EPS);
+    approx_eq(found[1].cost, 2.0, EPS);
+}
+
+#[test]
+fn test_undirected_grid_detours() {
+    // 6 shortest (4), then detours of 6 and 8: every loopless path
+    let g = grid_undirected(3, 3, 1.0);
+    let found = k_shortest_paths(&g, 0, 8, 20);
+
+    well_formed(&g, &found, 0, 8);
+    let costs: Vec<f64> = found.iter().map(|p| p.cost).collect();
+    assert_eq!(
+        costs,
+        vec![4.0, 4.0, 4.0, 4.0, 4.0, 4.0, 6.0, 6.0, 6.0, 6.0, 8.0, 8.0]
+    );
+}
+
+#[test]
+fn test_unreachable_target() {
+    let g = disconnected(6, 1.0);
+    assert!(k_shortest_paths(&g, 0, 4, 3).is_empty());
+}
+
+#[test]
+fn test_source_is_target() {
+    let g = linear(3, 1.0);
+    let found = k_shortest_paths(&g, 1, 1, 3);
+
+    assert_eq!(paths(&found), vec![vec![1]]);
+    approx_eq(found[0].cost, 0.0, EPS);
+}
+
+#[test]
+fn test_random_graphs() {
+    for seed in 1..10 {
+        let g = random::<f64>(25, 100, 9, seed);
+        let found = k_shortest_paths(&g, 0, 24, 8);
+        well_formed(&g, &found, 0, 24);
+
+        let mut buf = dynamic(25);
+        cheeky_dijkstra(&g, 0, &mut buf);
+        if let Some(first) = found.first() {
+            approx_eq(first.cost, buf.dist[24], EPS);
+        }
+    }
+}